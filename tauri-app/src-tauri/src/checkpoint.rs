@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::{collect_processes, config, refresh_process_list, ProcessInfo, SharedSystem, UserDirectory};
+
+// Évolution d'un processus présent aux deux relevés
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessChange {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_before: f32,
+    pub cpu_after: f32,
+    pub memory_before: u64,
+    pub memory_after: u64,
+    pub cpu_delta: f32,    // Points de pourcentage
+    pub memory_delta: i64, // Octets, négatif si la mémoire a diminué
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessDiff {
+    pub checkpoint: String,
+    pub taken_at: DateTime<Utc>,
+    pub new_processes: Vec<ProcessInfo>,
+    pub gone_processes: Vec<ProcessInfo>,
+    // Triés par variation CPU décroissante (en valeur absolue)
+    pub changed: Vec<ProcessChange>,
+}
+
+struct Checkpoint {
+    taken_at: DateTime<Utc>,
+    processes: Vec<ProcessInfo>,
+}
+
+// Relevés nommés, conservés en mémoire jusqu'à la fermeture de l'application
+#[derive(Default)]
+pub struct CheckpointState {
+    checkpoints: Mutex<HashMap<String, Checkpoint>>,
+}
+
+async fn current_processes(
+    shared: &SharedSystem,
+    users: &UserDirectory,
+    gpu_heuristic: &config::GpuHeuristic,
+) -> Result<Vec<ProcessInfo>, String> {
+    shared.prime().await;
+    let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
+    sys.refresh_cpu();
+    refresh_process_list(&mut sys);
+    Ok(collect_processes(&sys, users, gpu_heuristic))
+}
+
+// Un checkpoint du même nom est remplacé
+#[tauri::command]
+pub async fn checkpoint(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    checkpoints: tauri::State<'_, CheckpointState>,
+    name: String,
+) -> Result<(), String> {
+    let processes = current_processes(&shared, &users, &config.snapshot().gpu_heuristic).await?;
+    let checkpoint = Checkpoint {
+        taken_at: Utc::now(),
+        processes,
+    };
+    checkpoints
+        .checkpoints
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(name, checkpoint);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn compare_checkpoint(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    checkpoints: tauri::State<'_, CheckpointState>,
+    name: String,
+) -> Result<ProcessDiff, String> {
+    let (taken_at, before) = {
+        let checkpoints = checkpoints.checkpoints.lock().map_err(|e| e.to_string())?;
+        let checkpoint = checkpoints
+            .get(&name)
+            .ok_or_else(|| format!("No checkpoint named {}", name))?;
+        (checkpoint.taken_at, checkpoint.processes.clone())
+    };
+    let now = current_processes(&shared, &users, &config.snapshot().gpu_heuristic).await?;
+
+    // Un pid réutilisé par un autre programme compte comme un départ et une arrivée
+    let key = |p: &ProcessInfo| (p.pid, p.name.clone());
+    let mut before: HashMap<(u32, String), ProcessInfo> = before.into_iter().map(|p| (key(&p), p)).collect();
+
+    let mut diff = ProcessDiff {
+        checkpoint: name,
+        taken_at,
+        new_processes: Vec::new(),
+        gone_processes: Vec::new(),
+        changed: Vec::new(),
+    };
+    for process in now {
+        match before.remove(&key(&process)) {
+            Some(old) => diff.changed.push(ProcessChange {
+                pid: process.pid,
+                name: process.name,
+                cpu_before: old.cpu_usage,
+                cpu_after: process.cpu_usage,
+                memory_before: old.memory,
+                memory_after: process.memory,
+                cpu_delta: process.cpu_usage - old.cpu_usage,
+                memory_delta: process.memory as i64 - old.memory as i64,
+            }),
+            None => diff.new_processes.push(process),
+        }
+    }
+    diff.gone_processes = before.into_values().collect();
+
+    diff.new_processes.sort_by_key(|p| p.pid);
+    diff.gone_processes.sort_by_key(|p| p.pid);
+    diff.changed
+        .sort_by(|a, b| b.cpu_delta.abs().total_cmp(&a.cpu_delta.abs()));
+    Ok(diff)
+}
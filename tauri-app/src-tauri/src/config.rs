@@ -0,0 +1,215 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, PoisonError};
+
+// Pondération du score d'impact ; les poids sont normalisés par leur somme
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ImpactWeights {
+    pub cpu: f64,
+    pub memory: f64,
+    pub disk: f64,
+}
+
+impl Default for ImpactWeights {
+    fn default() -> Self {
+        Self { cpu: 0.5, memory: 0.3, disk: 0.2 }
+    }
+}
+
+// Base des conversions d'octets en "Go" : 1024 (Gio, binaire, historique de l'application)
+// ou 1000 (Go, décimal, comme les fabricants de disques)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct UnitBase(u32);
+
+impl Default for UnitBase {
+    fn default() -> Self {
+        UnitBase(1024)
+    }
+}
+
+impl TryFrom<u32> for UnitBase {
+    type Error = String;
+
+    fn try_from(base: u32) -> Result<Self, Self::Error> {
+        match base {
+            1000 | 1024 => Ok(UnitBase(base)),
+            _ => Err(format!("Unit base must be 1000 or 1024, got {}", base)),
+        }
+    }
+}
+
+impl From<UnitBase> for u32 {
+    fn from(base: UnitBase) -> u32 {
+        base.0
+    }
+}
+
+impl UnitBase {
+    pub fn is_binary(self) -> bool {
+        self.0 == 1024
+    }
+
+    pub fn gigabytes(self, bytes: u64) -> f64 {
+        bytes as f64 / (self.0 as f64).powi(3)
+    }
+
+    // Libellé exact de l'unité produite par gigabytes()
+    pub fn gigabyte_label(self) -> &'static str {
+        if self.is_binary() {
+            "GiB"
+        } else {
+            "GB"
+        }
+    }
+}
+
+// Poids de chaque facteur du score de santé ; normalisés par la somme des poids des
+// facteurs disponibles (pas de seuil critique => la température est ignorée, l'activité
+// du swap n'est mesurée que sous Linux)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthWeights {
+    pub cpu: f64,
+    pub memory: f64,
+    pub disk: f64,
+    pub swap: f64,
+    pub temperature: f64,
+}
+
+impl Default for HealthWeights {
+    fn default() -> Self {
+        Self { cpu: 0.25, memory: 0.25, disk: 0.2, swap: 0.15, temperature: 0.15 }
+    }
+}
+
+// Détection des rafales de créations de processus (fork bombs, scripts emballés)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForkStormSettings {
+    // Nombre de nouveaux processus par tick du monitoring au-delà duquel l'événement est émis
+    pub threshold: usize,
+}
+
+impl Default for ForkStormSettings {
+    fn default() -> Self {
+        Self { threshold: 50 }
+    }
+}
+
+// Alerte de perte de paquets, évaluée à chaque tick du monitoring et par interface
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PacketLossSettings {
+    pub threshold_percent: f64,
+    // En deçà, l'interface est trop peu active pour qu'un pourcentage ait un sens
+    pub min_packets: u64,
+}
+
+impl Default for PacketLossSettings {
+    fn default() -> Self {
+        Self { threshold_percent: 2.0, min_packets: 100 }
+    }
+}
+
+// Estimation GPU d'un processus quand aucune mesure réelle n'existe : une fraction de
+// son usage CPU normalisé, plafonnée
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GpuEstimate {
+    pub multiplier: f32,
+    pub max: f32,
+}
+
+impl GpuEstimate {
+    pub fn apply(&self, cpu_usage: f32) -> f32 {
+        (cpu_usage * self.multiplier).min(self.max)
+    }
+}
+
+// Le processus correspond si son nom contient l'un des motifs
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GpuHeuristicRule {
+    pub patterns: Vec<String>,
+    #[serde(flatten)]
+    pub estimate: GpuEstimate,
+}
+
+// Première règle correspondante appliquée, sinon `default`. Notre propre processus est
+// reconnu par son pid, pas par son nom.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct GpuHeuristic {
+    pub rules: Vec<GpuHeuristicRule>,
+    pub own_process: GpuEstimate,
+    pub default: GpuEstimate,
+}
+
+impl Default for GpuHeuristic {
+    fn default() -> Self {
+        let rule = |patterns: &[&str], multiplier, max| GpuHeuristicRule {
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            estimate: GpuEstimate { multiplier, max },
+        };
+        Self {
+            rules: vec![
+                rule(&["chrome", "firefox", "edge"], 0.3, 15.0), // Navigateurs : un peu de GPU
+                rule(&["game", "unity", "unreal"], 2.0, 85.0),   // Jeux : beaucoup de GPU
+                rule(&["nvidia", "amd", "gpu"], 1.5, 25.0),      // Processus liés au GPU
+            ],
+            own_process: GpuEstimate { multiplier: 0.1, max: 5.0 },
+            default: GpuEstimate { multiplier: 0.05, max: 3.0 },
+        }
+    }
+}
+
+impl GpuHeuristic {
+    pub fn estimate(&self, name: &str, pid: u32, cpu_usage: f32) -> f32 {
+        let estimate = if pid == std::process::id() {
+            &self.own_process
+        } else {
+            self.rules
+                .iter()
+                .find(|rule| rule.patterns.iter().any(|p| name.contains(p.as_str())))
+                .map(|rule| &rule.estimate)
+                .unwrap_or(&self.default)
+        };
+        estimate.apply(cpu_usage)
+    }
+}
+
+// Réglages modifiables à chaud depuis le frontend
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AppConfig {
+    pub impact_weights: ImpactWeights,
+    pub gpu_heuristic: GpuHeuristic,
+    pub health_weights: HealthWeights,
+    pub fork_storm: ForkStormSettings,
+    pub packet_loss: PacketLossSettings,
+    pub unit_base: UnitBase,
+}
+
+#[derive(Default)]
+pub struct ConfigState(pub Mutex<AppConfig>);
+
+impl ConfigState {
+    // Une configuration reste lisible même si un écrivain a paniqué en tenant le verrou :
+    // le thread de monitoring la lit à chaque tick et ne doit pas mourir pour autant.
+    pub fn snapshot(&self) -> AppConfig {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+}
+
+#[tauri::command]
+pub fn get_config(state: tauri::State<'_, ConfigState>) -> Result<AppConfig, String> {
+    Ok(state.snapshot())
+}
+
+#[tauri::command]
+pub fn set_config(state: tauri::State<'_, ConfigState>, config: AppConfig) -> Result<(), String> {
+    *state.0.lock().map_err(|e| e.to_string())? = config;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_unit_base(state: tauri::State<'_, ConfigState>, base: u32) -> Result<(), String> {
+    let base = UnitBase::try_from(base)?;
+    state.0.lock().map_err(|e| e.to_string())?.unit_base = base;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// Connexions TCP (IPv4 et IPv6) par état, ex. {"ESTABLISHED": 42, "TIME_WAIT": 7}.
+// Seuls les états présents figurent dans la table.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConnectionSummary {
+    pub total: usize,
+    pub by_state: BTreeMap<String, usize>,
+}
+
+#[tauri::command]
+pub fn get_connection_summary() -> Result<ConnectionSummary, String> {
+    let sockets = get_sockets_info(AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6, ProtocolFlags::TCP)
+        .map_err(|e| format!("Cannot list TCP sockets: {}", e))?;
+
+    let mut by_state: BTreeMap<String, usize> = BTreeMap::new();
+    for socket in &sockets {
+        if let ProtocolSocketInfo::Tcp(tcp) = &socket.protocol_socket_info {
+            *by_state.entry(tcp.state.to_string()).or_default() += 1;
+        }
+    }
+
+    Ok(ConnectionSummary {
+        total: by_state.values().sum(),
+        by_state,
+    })
+}
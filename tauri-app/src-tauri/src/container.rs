@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+// Dans un conteneur, sysinfo voit la mémoire et les CPU de l'hôte : les limites du
+// cgroup permettent à l'UI d'afficher ce dont l'application dispose réellement.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ContainerInfo {
+    pub in_container: bool,
+    pub runtime: Option<String>, // "docker", "podman", "kubernetes", "lxc" ou "wsl"
+    pub cgroup_version: Option<u8>,
+    // None si aucune limite n'est posée sur le cgroup
+    pub memory_limit: Option<u64>,
+    pub memory_usage: Option<u64>,
+    pub cpu_limit: Option<f64>, // En nombre de cœurs, ex. 1.5
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::ContainerInfo;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+    // Valeur "illimitée" de cgroup v1, arrondie à la page : tout ce qui dépasse est sans limite
+    const V1_UNLIMITED: u64 = 1 << 60;
+
+    fn read_trimmed(path: &Path) -> Option<String> {
+        fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+    }
+
+    fn detect_runtime(proc1_cgroup: &str) -> Option<String> {
+        let runtime = if Path::new("/.dockerenv").exists() {
+            "docker"
+        } else if Path::new("/run/.containerenv").exists() {
+            "podman"
+        } else if proc1_cgroup.contains("kubepods") {
+            "kubernetes"
+        } else if proc1_cgroup.contains("docker") {
+            "docker"
+        } else if proc1_cgroup.contains("lxc")
+            || fs::read("/proc/1/environ").is_ok_and(|env| env.windows(13).any(|w| w == b"container=lxc"))
+        {
+            "lxc"
+        } else if read_trimmed(Path::new("/proc/sys/kernel/osrelease"))
+            .is_some_and(|r| r.to_lowercase().contains("microsoft"))
+        {
+            "wsl"
+        } else {
+            return None;
+        };
+        Some(runtime.to_string())
+    }
+
+    // Le chemin de /proc/self/cgroup est celui vu par l'hôte ; sans espace de noms cgroup
+    // on le retrouve sous la racine, sinon le conteneur voit son propre cgroup à la racine.
+    fn cgroup_dir(controller_root: &Path, relative: &str) -> PathBuf {
+        let nested = controller_root.join(relative.trim_start_matches('/'));
+        if nested.is_dir() {
+            nested
+        } else {
+            controller_root.to_path_buf()
+        }
+    }
+
+    fn read_v2(info: &mut ContainerInfo, relative: &str) {
+        let dir = cgroup_dir(Path::new(CGROUP_ROOT), relative);
+        info.memory_limit = read_trimmed(&dir.join("memory.max")).and_then(|m| m.parse().ok());
+        info.memory_usage = read_trimmed(&dir.join("memory.current")).and_then(|m| m.parse().ok());
+        // "max 100000" ou "<quota> <période>" en microsecondes
+        info.cpu_limit = read_trimmed(&dir.join("cpu.max")).and_then(|cpu| {
+            let (quota, period) = cpu.split_once(' ')?;
+            Some(quota.parse::<f64>().ok()? / period.parse::<f64>().ok()?)
+        });
+    }
+
+    fn read_v1(info: &mut ContainerInfo, controllers: &[(String, String)]) {
+        let dir_of = |name: &str| {
+            controllers
+                .iter()
+                .find(|(list, _)| list.split(',').any(|c| c == name))
+                .map(|(_, relative)| cgroup_dir(&Path::new(CGROUP_ROOT).join(name), relative))
+        };
+        let read_u64 = |dir: &Path, file: &str| read_trimmed(&dir.join(file)).and_then(|v| v.parse::<u64>().ok());
+
+        if let Some(memory) = dir_of("memory") {
+            info.memory_limit = read_u64(&memory, "memory.limit_in_bytes").filter(|&l| l < V1_UNLIMITED);
+            info.memory_usage = read_u64(&memory, "memory.usage_in_bytes");
+        }
+        if let Some(cpu) = dir_of("cpu") {
+            // Quota à -1 : pas de limite (sa lecture en u64 échoue)
+            let quota = read_u64(&cpu, "cpu.cfs_quota_us");
+            let period = read_u64(&cpu, "cpu.cfs_period_us").filter(|&p| p > 0);
+            info.cpu_limit = quota.zip(period).map(|(q, p)| q as f64 / p as f64);
+        }
+    }
+
+    pub fn detect() -> Result<ContainerInfo, String> {
+        let own = fs::read_to_string("/proc/self/cgroup").map_err(|e| format!("Cannot read /proc/self/cgroup: {}", e))?;
+        // /proc/1/cgroup peut être illisible (hidepid) : on se rabat sur notre propre cgroup
+        let proc1 = fs::read_to_string("/proc/1/cgroup").unwrap_or_else(|_| own.clone());
+
+        let runtime = detect_runtime(&proc1);
+        let mut info = ContainerInfo {
+            in_container: runtime.is_some(),
+            runtime,
+            ..Default::default()
+        };
+
+        // Lignes "<id>:<contrôleurs>:<chemin>" ; v2 pur = une seule ligne "0::<chemin>"
+        let controllers: Vec<(String, String)> = own
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(3, ':');
+                let _id = parts.next()?;
+                Some((parts.next()?.to_string(), parts.next()?.to_string()))
+            })
+            .collect();
+
+        if Path::new(CGROUP_ROOT).join("cgroup.controllers").exists() {
+            info.cgroup_version = Some(2);
+            let relative = controllers.iter().find(|(c, _)| c.is_empty()).map(|(_, p)| p.as_str()).unwrap_or("/");
+            read_v2(&mut info, relative);
+        } else if Path::new(CGROUP_ROOT).join("memory").is_dir() {
+            info.cgroup_version = Some(1);
+            read_v1(&mut info, &controllers);
+        }
+
+        Ok(info)
+    }
+}
+
+#[tauri::command]
+pub fn get_container_info() -> Result<ContainerInfo, String> {
+    #[cfg(target_os = "linux")]
+    {
+        linux::detect()
+    }
+
+    // Pas de cgroups hors Linux : l'application tourne directement sur l'hôte
+    #[cfg(not(target_os = "linux"))]
+    {
+        Ok(ContainerInfo::default())
+    }
+}
@@ -0,0 +1,60 @@
+// Tailles totales des caches CPU en Ko, toutes instances confondues : un L2 privé de
+// 1 Mo sur 8 cœurs donne 8192, un L3 partagé de 32 Mo donne 32768.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CacheSizes {
+    pub l1_kb: Option<u64>, // Données + instructions
+    pub l2_kb: Option<u64>,
+    pub l3_kb: Option<u64>,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn detect(logical_cpus: usize, physical_cores: usize) -> CacheSizes {
+    use raw_cpuid::{CacheType, CpuId};
+
+    let cpuid = CpuId::new();
+    let mut sizes = CacheSizes::default();
+
+    // Feuille 4 (Intel) ou 0x8000001D (AMD Zen) : une entrée par niveau et par type,
+    // avec le nombre de processeurs logiques qui partagent chaque instance
+    if let Some(caches) = cpuid.get_cache_parameters() {
+        for cache in caches {
+            if cache.cache_type() == CacheType::Null {
+                continue;
+            }
+            let instance_bytes = (cache.associativity()
+                * cache.physical_line_partitions()
+                * cache.coherency_line_size()
+                * cache.sets()) as u64;
+            let instances = logical_cpus.max(1).div_ceil(cache.max_cores_for_cache().max(1)) as u64;
+            let total_kb = instance_bytes * instances / 1024;
+
+            let slot = match cache.level() {
+                1 => &mut sizes.l1_kb,
+                2 => &mut sizes.l2_kb,
+                3 => &mut sizes.l3_kb,
+                _ => continue,
+            };
+            *slot = Some(slot.unwrap_or(0) + total_kb);
+        }
+    }
+
+    // Anciens AMD : tailles par cœur (L1/L2) et L3 en unités de 512 Ko pour le package
+    if sizes.l1_kb.is_none() {
+        let cores = physical_cores.max(1) as u64;
+        if let Some(l1) = cpuid.get_l1_cache_and_tlb_info() {
+            sizes.l1_kb = Some((l1.dcache_size() as u64 + l1.icache_size() as u64) * cores);
+        }
+        if let Some(l2_l3) = cpuid.get_l2_l3_cache_and_tlb_info() {
+            sizes.l2_kb = Some(l2_l3.l2cache_size() as u64 * cores);
+            sizes.l3_kb = Some(l2_l3.l3cache_size() as u64 * 512).filter(|&kb| kb > 0);
+        }
+    }
+
+    sizes
+}
+
+// CPUID n'existe que sur x86 ; ailleurs, les tailles restent inconnues
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+pub fn detect(_logical_cpus: usize, _physical_cores: usize) -> CacheSizes {
+    CacheSizes::default()
+}
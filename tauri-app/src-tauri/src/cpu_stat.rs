@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CpuTimeBreakdown {
+    pub user_percent: f64,
+    pub system_percent: f64,
+    pub idle_percent: f64,
+    pub iowait_percent: f64,
+    // Temps passé dans les gestionnaires d'interruptions : une valeur élevée pointe un pilote
+    // (réseau, contrôleur disque) ou un matériel défaillant
+    pub irq_percent: f64,
+    pub softirq_percent: f64,
+}
+
+// Compteurs cumulés (en jiffies) de la ligne "cpu" agrégée de /proc/stat
+#[derive(Debug, Clone, Copy, Default)]
+struct CpuTimes {
+    user: u64,
+    nice: u64,
+    system: u64,
+    idle: u64,
+    iowait: u64,
+    irq: u64,
+    softirq: u64,
+    steal: u64,
+}
+
+impl CpuTimes {
+    // guest et guest_nice sont déjà comptés dans user/nice, on ne les additionne pas
+    fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq + self.steal
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn parse_cpu_line(content: &str) -> Option<CpuTimes> {
+    let line = content.lines().find(|l| l.starts_with("cpu "))?;
+    let fields: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .map(|f| f.parse().unwrap_or(0))
+        .collect();
+
+    let field = |i: usize| fields.get(i).copied().unwrap_or(0);
+    Some(CpuTimes {
+        user: field(0),
+        nice: field(1),
+        system: field(2),
+        idle: field(3),
+        iowait: field(4),
+        irq: field(5),
+        softirq: field(6),
+        steal: field(7),
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_cpu_times() -> Option<CpuTimes> {
+    parse_cpu_line(&std::fs::read_to_string("/proc/stat").ok()?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_cpu_times() -> Option<CpuTimes> {
+    None
+}
+
+fn breakdown(before: CpuTimes, after: CpuTimes) -> Option<CpuTimeBreakdown> {
+    let total = after.total().saturating_sub(before.total());
+    if total == 0 {
+        return None;
+    }
+
+    let percent = |a: u64, b: u64| a.saturating_sub(b) as f64 / total as f64 * 100.0;
+    Some(CpuTimeBreakdown {
+        user_percent: percent(after.user + after.nice, before.user + before.nice),
+        system_percent: percent(after.system, before.system),
+        idle_percent: percent(after.idle, before.idle),
+        iowait_percent: percent(after.iowait, before.iowait),
+        irq_percent: percent(after.irq, before.irq),
+        softirq_percent: percent(after.softirq, before.softirq),
+    })
+}
+
+// Répartition user/system/idle/iowait/irq/softirq sur 200ms. `None` hors Linux.
+#[tauri::command]
+pub async fn get_cpu_breakdown() -> Result<Option<CpuTimeBreakdown>, String> {
+    let Some(before) = read_cpu_times() else {
+        return Ok(None);
+    };
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    Ok(read_cpu_times().and_then(|after| breakdown(before, after)))
+}
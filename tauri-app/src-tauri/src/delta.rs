@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::{collect_processes, config, refresh_process_list, ProcessInfo, SharedSystem, UserDirectory};
+
+// Variations en deçà desquelles une valeur est considérée inchangée
+const PERCENT_THRESHOLD: f64 = 0.5; // Points de pourcentage
+const PROCESS_MEMORY_THRESHOLD: u64 = 1024 * 1024;
+
+// Modifications depuis la dernière réponse. `full` vaut vrai au premier appel (ou sur
+// reset) : tous les champs sont alors présents et le frontend remplace son état.
+// Sinon, un champ absent ou un processus non listé n'a pas changé.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StatsDelta {
+    pub full: bool,
+    pub timestamp: DateTime<Utc>,
+    pub cpu_usage: Option<f64>,
+    pub memory_usage: Option<f64>,
+    pub memory_used: Option<u64>,
+    pub processes_changed: Vec<ProcessInfo>, // Nouveaux ou modifiés, complets
+    pub processes_removed: Vec<u32>,
+}
+
+// Ce que le frontend a reçu en dernier : seules les valeurs envoyées y sont mises à jour,
+// pour que de petites dérives successives finissent par dépasser le seuil
+#[derive(Default)]
+struct Sent {
+    cpu_usage: f64,
+    memory_usage: f64,
+    processes: HashMap<u32, ProcessInfo>,
+}
+
+#[derive(Default)]
+pub struct DeltaState {
+    sent: Mutex<Option<Sent>>,
+}
+
+fn changed(before: f64, now: f64) -> bool {
+    (before - now).abs() >= PERCENT_THRESHOLD
+}
+
+fn process_changed(before: &ProcessInfo, now: &ProcessInfo) -> bool {
+    // Un nom différent signifie que le pid a été réutilisé
+    before.name != now.name
+        || changed(before.cpu_usage as f64, now.cpu_usage as f64)
+        || changed(before.gpu_usage as f64, now.gpu_usage as f64)
+        || before.memory.abs_diff(now.memory) >= PROCESS_MEMORY_THRESHOLD
+}
+
+#[tauri::command]
+pub async fn get_stats_delta(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    delta: tauri::State<'_, DeltaState>,
+    reset: Option<bool>,
+) -> Result<StatsDelta, String> {
+    shared.prime().await;
+    let gpu_heuristic = config.snapshot().gpu_heuristic;
+
+    let (cpu_usage, memory_usage, memory_used, processes) = {
+        let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
+        sys.refresh_cpu();
+        sys.refresh_memory();
+        refresh_process_list(&mut sys);
+        (
+            sys.global_cpu_info().cpu_usage() as f64,
+            sys.used_memory() as f64 / sys.total_memory().max(1) as f64 * 100.0,
+            sys.used_memory(),
+            collect_processes(&sys, &users, &gpu_heuristic),
+        )
+    };
+
+    let mut sent = delta.sent.lock().map_err(|e| e.to_string())?;
+    if reset.unwrap_or(false) {
+        *sent = None;
+    }
+    let full = sent.is_none();
+    let sent = sent.get_or_insert_with(Sent::default);
+
+    let mut result = StatsDelta {
+        full,
+        timestamp: Utc::now(),
+        cpu_usage: None,
+        memory_usage: None,
+        memory_used: None,
+        processes_changed: Vec::new(),
+        processes_removed: Vec::new(),
+    };
+
+    if full || changed(sent.cpu_usage, cpu_usage) {
+        sent.cpu_usage = cpu_usage;
+        result.cpu_usage = Some(cpu_usage);
+    }
+    if full || changed(sent.memory_usage, memory_usage) {
+        sent.memory_usage = memory_usage;
+        result.memory_usage = Some(memory_usage);
+        result.memory_used = Some(memory_used);
+    }
+
+    let current: HashMap<u32, ProcessInfo> = processes.into_iter().map(|p| (p.pid, p)).collect();
+    result.processes_removed = sent.processes.keys().filter(|pid| !current.contains_key(pid)).copied().collect();
+    for pid in &result.processes_removed {
+        sent.processes.remove(pid);
+    }
+    for (pid, process) in current {
+        let is_new_or_changed = sent.processes.get(&pid).is_none_or(|before| process_changed(before, &process));
+        if is_new_or_changed {
+            result.processes_changed.push(process.clone());
+            sent.processes.insert(pid, process);
+        }
+    }
+    result.processes_changed.sort_by_key(|p| p.pid);
+    result.processes_removed.sort();
+
+    Ok(result)
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+// Compteurs d'erreurs ECC cumulés sur tous les contrôleurs mémoire depuis le démarrage
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MemoryErrors {
+    pub correctable: u64,
+    pub uncorrectable: u64,
+}
+
+// EDAC expose un répertoire mcN par contrôleur mémoire ; il n'existe que si un driver
+// EDAC est chargé, c'est-à-dire en pratique sur du matériel ECC.
+#[cfg(target_os = "linux")]
+fn read_memory_errors() -> Result<Option<MemoryErrors>, String> {
+    use std::path::Path;
+
+    let Ok(controllers) = std::fs::read_dir("/sys/devices/system/edac/mc") else {
+        return Ok(None);
+    };
+
+    let read_count = |dir: &Path, file: &str| -> Result<u64, String> {
+        let path = dir.join(file);
+        std::fs::read_to_string(&path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?
+            .trim()
+            .parse()
+            .map_err(|e| format!("Invalid EDAC counter {}: {}", path.display(), e))
+    };
+
+    let mut errors: Option<MemoryErrors> = None;
+    for controller in controllers.flatten() {
+        if !controller.file_name().to_string_lossy().starts_with("mc") {
+            continue;
+        }
+        let dir = controller.path();
+        let total = errors.get_or_insert(MemoryErrors { correctable: 0, uncorrectable: 0 });
+        total.correctable += read_count(&dir, "ce_count")?;
+        total.uncorrectable += read_count(&dir, "ue_count")?;
+    }
+
+    Ok(errors)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_memory_errors() -> Result<Option<MemoryErrors>, String> {
+    Ok(None)
+}
+
+// Ok(None) sans mémoire ECC (ou sans driver EDAC)
+#[tauri::command]
+pub fn get_memory_errors() -> Result<Option<MemoryErrors>, String> {
+    read_memory_errors()
+}
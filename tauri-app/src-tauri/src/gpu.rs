@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use nvml_wrapper::enum_wrappers::device::{TemperatureSensor, TemperatureThreshold};
+use nvml_wrapper::Nvml;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::{integrated_gpu, TemperatureInfo};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GpuInfo {
+    pub index: usize,
+    pub name: String,
+    pub pci_bus_id: String,
+    pub uuid: String,
+    pub usage: f32,
+    pub memory_total: u64,
+    pub memory_used: u64,
+    pub temperature: Option<f32>,
+    // Moteurs NVENC/NVDEC, 0 si la carte ne les expose pas
+    pub encoder_util: f32,
+    pub decoder_util: f32,
+    // GPU intégré : sa mémoire est prélevée sur la RAM système (0 si inconnu)
+    pub is_integrated: bool,
+    pub shared_memory_total: u64,
+    pub shared_memory_used: u64,
+    // Consommation instantanée et plafond appliqué par le driver ; None sans télémétrie
+    pub power_draw_watts: Option<f32>,
+    pub power_limit_watts: Option<f32>,
+    // Ventilateur le plus rapide sur les cartes qui en ont plusieurs ; None sur une carte passive
+    pub fan_speed_rpm: Option<u32>,
+    pub fan_speed_percent: Option<f32>,
+}
+
+// Mesure thermique d'un GPU, enregistrée par le thread de monitoring
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GpuSample {
+    pub timestamp: DateTime<Utc>,
+    pub index: usize,
+    pub temperature: Option<f32>,
+    // Même mesure que GpuInfo::fan_speed_percent : le ventilateur le plus rapide
+    pub fan_speed_percent: Option<f32>,
+}
+
+// NVML n'est initialisé qu'une seule fois : l'init charge la bibliothèque du driver
+// et coûte plusieurs dizaines de millisecondes. Un échec est aussi mémorisé,
+// le driver n'apparaîtra pas en cours d'exécution.
+static NVML: OnceLock<Result<Nvml, String>> = OnceLock::new();
+
+pub fn nvml() -> Result<&'static Nvml, String> {
+    NVML.get_or_init(|| Nvml::init().map_err(|e| format!("NVML unavailable: {}", e)))
+        .as_ref()
+        .map_err(|e| e.clone())
+}
+
+// Vitesse maximale (tr/min, %) parmi les ventilateurs de la carte. Le régime en tr/min
+// n'est exposé que par les drivers récents (R535+) : il peut manquer alors que le % est connu.
+fn fan_speeds(device: &nvml_wrapper::Device) -> (Option<u32>, Option<f32>) {
+    // Les drivers anciens n'implémentent pas le comptage : on tente au moins le premier
+    let fans = device.num_fans().unwrap_or(1);
+    let rpm = (0..fans).filter_map(|fan| device.fan_speed_rpm(fan).ok()).max();
+    let percent = (0..fans).filter_map(|fan| device.fan_speed(fan).ok()).max();
+    (rpm, percent.map(|p| p as f32))
+}
+
+pub fn read_gpus() -> Result<Vec<GpuInfo>, String> {
+    let nvml = nvml()?;
+    let count = nvml.device_count().map_err(|e| e.to_string())?;
+
+    let mut gpus = Vec::with_capacity(count as usize);
+    for i in 0..count {
+        let device = nvml.device_by_index(i).map_err(|e| e.to_string())?;
+
+        // L'index NVML suit l'ordre PCI, il est donc stable d'un redémarrage à l'autre
+        // tant que les cartes ne changent pas de slot. Le bus ID identifie le slot lui-même.
+        let pci_bus_id = device.pci_info().map(|p| p.bus_id).unwrap_or_default();
+        let memory = device.memory_info().ok();
+        let (fan_speed_rpm, fan_speed_percent) = fan_speeds(&device);
+
+        gpus.push(GpuInfo {
+            index: device.index().map(|idx| idx as usize).unwrap_or(i as usize),
+            name: device.name().unwrap_or_default(),
+            pci_bus_id,
+            uuid: device.uuid().unwrap_or_default(),
+            usage: device.utilization_rates().map(|u| u.gpu as f32).unwrap_or(0.0),
+            memory_total: memory.as_ref().map(|m| m.total).unwrap_or(0),
+            memory_used: memory.as_ref().map(|m| m.used).unwrap_or(0),
+            temperature: device.temperature(TemperatureSensor::Gpu).ok().map(|t| t as f32),
+            encoder_util: device.encoder_utilization().map(|u| u.utilization as f32).unwrap_or(0.0),
+            decoder_util: device.decoder_utilization().map(|u| u.utilization as f32).unwrap_or(0.0),
+            is_integrated: false,
+            shared_memory_total: 0,
+            shared_memory_used: 0,
+            // NVML exprime les puissances en milliwatts
+            power_draw_watts: device.power_usage().ok().map(|mw| mw as f32 / 1000.0),
+            power_limit_watts: device.enforced_power_limit().ok().map(|mw| mw as f32 / 1000.0),
+            fan_speed_rpm,
+            fan_speed_percent,
+        });
+    }
+
+    Ok(gpus)
+}
+
+// Lecture légère (température et ventilateur uniquement) pour l'historique
+pub fn sample_gpus() -> Result<Vec<GpuSample>, String> {
+    let nvml = nvml()?;
+    let count = nvml.device_count().map_err(|e| e.to_string())?;
+    let timestamp = Utc::now();
+
+    (0..count)
+        .map(|i| {
+            let device = nvml.device_by_index(i).map_err(|e| e.to_string())?;
+            Ok(GpuSample {
+                timestamp,
+                index: i as usize,
+                temperature: device.temperature(TemperatureSensor::Gpu).ok().map(|t| t as f32),
+                // Les cartes passives n'ont pas de ventilateur : None
+                fan_speed_percent: fan_speeds(&device).1,
+            })
+        })
+        .collect()
+}
+
+// Le driver propriétaire NVIDIA n'expose pas de capteur hwmon : ses températures ne
+// remontent que par NVML. Seuil de ralentissement en max, d'arrêt en critique.
+pub fn nvidia_temperatures() -> Vec<TemperatureInfo> {
+    let Ok(nvml) = nvml() else {
+        return Vec::new();
+    };
+    let count = nvml.device_count().unwrap_or(0);
+
+    (0..count)
+        .filter_map(|i| {
+            let device = nvml.device_by_index(i).ok()?;
+            let threshold = |kind| device.temperature_threshold(kind).ok().map(|t| t as f32);
+            Some(TemperatureInfo {
+                component: device.name().unwrap_or_else(|_| format!("GPU {}", i)),
+                temperature: device.temperature(TemperatureSensor::Gpu).ok()? as f32,
+                max_temperature: threshold(TemperatureThreshold::Slowdown),
+                critical_temperature: threshold(TemperatureThreshold::Shutdown),
+            })
+        })
+        .collect()
+}
+
+// GPU NVIDIA via NVML puis GPU intégrés (Intel/AMD), détectés séparément.
+// Les index des GPU intégrés suivent ceux de NVML.
+pub fn all_gpus() -> Vec<GpuInfo> {
+    let mut gpus = read_gpus().unwrap_or_default();
+    let first_index = gpus.len();
+    gpus.extend(
+        integrated_gpu::detect()
+            .into_iter()
+            .enumerate()
+            .map(|(i, gpu)| GpuInfo { index: first_index + i, ..gpu }),
+    );
+    gpus
+}
+
+#[tauri::command]
+pub fn get_gpu_info() -> Result<Vec<GpuInfo>, String> {
+    Ok(all_gpus())
+}
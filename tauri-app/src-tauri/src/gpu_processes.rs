@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use sysinfo::{Pid, System};
+
+// Utilisation GPU réelle d'un processus sur une carte, contrairement à l'estimation de
+// ProcessInfo.gpu_usage. Un processus actif sur plusieurs GPU a une entrée par carte.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GpuProcess {
+    pub pid: u32,
+    pub name: String,
+    // Même identifiant que GpuInfo.pci_bus_id : bus PCI via NVML, "luid:..." via les compteurs
+    pub gpu_id: String,
+    // GpuInfo.index / GpuSample.index de la carte, si elle figure dans get_gpu_info
+    pub gpu_index: Option<usize>,
+    pub gpu_usage: Option<f32>,        // Moteur le plus chargé, en %
+    pub dedicated_memory: Option<u64>, // VRAM
+    pub shared_memory: Option<u64>,    // RAM système mappée pour le GPU (iGPU)
+    pub source: String,                // "nvml" ou "performance-counters"
+}
+
+#[derive(Default)]
+struct Usage {
+    gpu_usage: Option<f32>,
+    dedicated_memory: Option<u64>,
+    shared_memory: Option<u64>,
+}
+
+fn max_option(current: Option<f32>, value: f32) -> Option<f32> {
+    Some(current.map_or(value, |c| c.max(value)))
+}
+
+// Utilisation et mémoire par (pid, identifiant de carte)
+type UsageByGpu = HashMap<(u32, String), Usage>;
+
+fn nvml_usage() -> Result<UsageByGpu, String> {
+    use nvml_wrapper::enums::device::UsedGpuMemory;
+
+    let nvml = crate::gpu::nvml()?;
+    let count = nvml.device_count().map_err(|e| e.to_string())?;
+    let mut usage = UsageByGpu::new();
+
+    for i in 0..count {
+        let device = nvml.device_by_index(i).map_err(|e| e.to_string())?;
+        // Même identifiant que gpu::read_gpus
+        let gpu_id = device.pci_info().map(|p| p.bus_id).unwrap_or_default();
+        let graphics = device.running_graphics_processes().unwrap_or_default();
+        let compute = device.running_compute_processes().unwrap_or_default();
+        for process in graphics.into_iter().chain(compute) {
+            let entry = usage.entry((process.pid, gpu_id.clone())).or_default();
+            // Sous WDDM, le driver Windows gère la mémoire et NVML ne la connaît pas
+            if let UsedGpuMemory::Used(bytes) = process.used_gpu_memory {
+                entry.dedicated_memory = Some(entry.dedicated_memory.unwrap_or(0) + bytes);
+            }
+        }
+
+        // Échantillons des dernières secondes : on garde le plus récent de chaque processus
+        let mut latest: HashMap<u32, (u64, u32)> = HashMap::new();
+        for sample in device.process_utilization_stats(None).unwrap_or_default() {
+            let slot = latest.entry(sample.pid).or_insert((0, 0));
+            if sample.timestamp >= slot.0 {
+                *slot = (
+                    sample.timestamp,
+                    sample.sm_util.max(sample.enc_util).max(sample.dec_util),
+                );
+            }
+        }
+        for (pid, (_, util)) in latest {
+            let entry = usage.entry((pid, gpu_id.clone())).or_default();
+            entry.gpu_usage = max_option(entry.gpu_usage, util as f32);
+        }
+    }
+
+    Ok(usage)
+}
+
+// Compteurs "GPU Engine" et "GPU Process Memory" (Windows 10 1709+), alimentés par WDDM
+// pour tous les GPU, intégrés compris. Instances de la forme
+// "pid_1234_luid_0x00000000_0x0000D1F2_phys_0_eng_3_engtype_3D".
+#[cfg(target_os = "windows")]
+fn performance_counter_usage() -> Result<UsageByGpu, String> {
+    use windows_sys::Win32::System::Performance::{
+        PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterArrayW, PdhOpenQueryW,
+        PDH_FMT_COUNTERVALUE_ITEM_W, PDH_FMT_DOUBLE, PDH_MORE_DATA,
+    };
+
+    // L'utilisation est un taux : deux collectes espacées sont nécessaires
+    const WINDOW: std::time::Duration = std::time::Duration::from_millis(500);
+    const PDH_CSTATUS_NEW_DATA: u32 = 1;
+
+    struct Query(isize);
+    impl Drop for Query {
+        fn drop(&mut self) {
+            unsafe { PdhCloseQuery(self.0) };
+        }
+    }
+
+    let add_counter = |query: &Query, path: &str| -> Result<isize, String> {
+        let wide: Vec<u16> = path.encode_utf16().chain(std::iter::once(0)).collect();
+        let mut counter = 0isize;
+        match unsafe { PdhAddEnglishCounterW(query.0, wide.as_ptr(), 0, &mut counter) } {
+            0 => Ok(counter),
+            status => Err(format!(
+                "GPU performance counter {} unavailable (status {:#x})",
+                path, status
+            )),
+        }
+    };
+
+    let read_counter = |counter: isize| -> Vec<(String, f64)> {
+        unsafe {
+            let (mut size, mut count) = (0u32, 0u32);
+            let status =
+                PdhGetFormattedCounterArrayW(counter, PDH_FMT_DOUBLE, &mut size, &mut count, std::ptr::null_mut());
+            if status != PDH_MORE_DATA {
+                return Vec::new();
+            }
+            // Tampon de u64 pour respecter l'alignement des structures renvoyées
+            let mut buffer = vec![0u64; size as usize / 8 + 1];
+            let items = buffer.as_mut_ptr() as *mut PDH_FMT_COUNTERVALUE_ITEM_W;
+            if PdhGetFormattedCounterArrayW(counter, PDH_FMT_DOUBLE, &mut size, &mut count, items) != 0 {
+                return Vec::new();
+            }
+            std::slice::from_raw_parts(items, count as usize)
+                .iter()
+                .filter(|item| item.FmtValue.CStatus <= PDH_CSTATUS_NEW_DATA)
+                .map(|item| {
+                    (
+                        crate::services::wide_to_string(item.szName),
+                        item.FmtValue.Anonymous.doubleValue,
+                    )
+                })
+                .collect()
+        }
+    };
+
+    let query = unsafe {
+        let mut handle = 0isize;
+        if PdhOpenQueryW(std::ptr::null(), 0, &mut handle) != 0 {
+            return Err("Cannot open a performance counter query".to_string());
+        }
+        Query(handle)
+    };
+    let engine = add_counter(&query, "\\GPU Engine(*)\\Utilization Percentage")?;
+    let dedicated = add_counter(&query, "\\GPU Process Memory(*)\\Dedicated Usage")?;
+    let shared = add_counter(&query, "\\GPU Process Memory(*)\\Shared Usage")?;
+
+    unsafe { PdhCollectQueryData(query.0) };
+    std::thread::sleep(WINDOW);
+    unsafe { PdhCollectQueryData(query.0) };
+
+    let pid_of = |instance: &str| instance.strip_prefix("pid_")?.split('_').next()?.parse::<u32>().ok();
+    // "luid_0x00000000_0x0000D1F2" -> "luid:000000000000d1f2", le format de integrated_gpu
+    let luid_of = |instance: &str| {
+        let (_, luid) = instance.split_once("_luid_")?;
+        let mut parts = luid.split('_').map(|part| u32::from_str_radix(part.trim_start_matches("0x"), 16).ok());
+        Some(format!("luid:{:08x}{:08x}", parts.next()??, parts.next()??))
+    };
+    let key_of = |instance: &str| Some((pid_of(instance)?, luid_of(instance)?));
+    let mut usage = UsageByGpu::new();
+
+    // Comme le Gestionnaire des tâches : somme des moteurs d'un même type sur un même
+    // adaptateur, puis maximum entre les types (3D, Copy, VideoDecode...)
+    let mut per_engine_type: HashMap<((u32, String), String), f64> = HashMap::new();
+    for (instance, value) in read_counter(engine) {
+        let Some(key) = key_of(&instance) else {
+            continue;
+        };
+        let engine_type = instance.rsplit_once("engtype_").map(|(_, t)| t.to_string()).unwrap_or_default();
+        *per_engine_type.entry((key, engine_type)).or_default() += value;
+    }
+    for ((key, _), value) in per_engine_type {
+        let entry = usage.entry(key).or_default();
+        entry.gpu_usage = max_option(entry.gpu_usage, value.min(100.0) as f32);
+    }
+
+    for (instance, value) in read_counter(dedicated) {
+        if let Some(key) = key_of(&instance) {
+            let entry = usage.entry(key).or_default();
+            entry.dedicated_memory = Some(entry.dedicated_memory.unwrap_or(0) + value as u64);
+        }
+    }
+    for (instance, value) in read_counter(shared) {
+        if let Some(key) = key_of(&instance) {
+            let entry = usage.entry(key).or_default();
+            entry.shared_memory = Some(entry.shared_memory.unwrap_or(0) + value as u64);
+        }
+    }
+
+    // Le pid 0 regroupe l'activité non attribuée (DWM, noyau)
+    usage.retain(|(pid, _), _| *pid != 0);
+    Ok(usage)
+}
+
+// Sous Windows, les compteurs de performances couvrent tous les GPU (NVML ne voit ni les
+// iGPU ni la mémoire sous WDDM) ; NVML ne sert que s'ils sont indisponibles.
+async fn gpu_usage_by_pid() -> Result<(UsageByGpu, &'static str), String> {
+    #[cfg(target_os = "windows")]
+    {
+        let counters = tokio::task::spawn_blocking(performance_counter_usage)
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Ok(usage) = counters {
+            return Ok((usage, "performance-counters"));
+        }
+    }
+    nvml_usage().map(|usage| (usage, "nvml"))
+}
+
+#[tauri::command]
+pub async fn get_gpu_processes() -> Result<Vec<GpuProcess>, String> {
+    let (usage, source) = gpu_usage_by_pid().await?;
+
+    let mut sys = System::new();
+    sys.refresh_processes();
+    // Les compteurs Windows identifient aussi les cartes NVIDIA par LUID, que seuls les
+    // GPU intégrés portent dans GpuInfo : leur index reste alors inconnu
+    let indexes: HashMap<String, usize> = crate::gpu::all_gpus()
+        .into_iter()
+        .filter(|gpu| !gpu.pci_bus_id.is_empty())
+        .map(|gpu| (gpu.pci_bus_id, gpu.index))
+        .collect();
+
+    let mut processes: Vec<GpuProcess> = usage
+        .into_iter()
+        .map(|((pid, gpu_id), usage)| GpuProcess {
+            pid,
+            name: sys
+                .process(Pid::from_u32(pid))
+                .map(|p| p.name().to_string())
+                .unwrap_or_default(),
+            gpu_index: indexes.get(&gpu_id).copied(),
+            gpu_id,
+            gpu_usage: usage.gpu_usage,
+            dedicated_memory: usage.dedicated_memory,
+            shared_memory: usage.shared_memory,
+            source: source.to_string(),
+        })
+        .collect();
+    // Plus gros consommateurs d'abord, la VRAM départageant les processus au repos
+    processes.sort_by(|a, b| {
+        let usage = |p: &GpuProcess| p.gpu_usage.unwrap_or(0.0);
+        usage(b)
+            .total_cmp(&usage(a))
+            .then(b.dedicated_memory.cmp(&a.dedicated_memory))
+    });
+    Ok(processes)
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use sysinfo::{Components, Disks};
+
+use crate::{config, memory_detail, SharedSystem};
+
+// Espace libre à partir duquel un disque est considéré sain
+const HEALTHY_DISK_FREE_PERCENT: f64 = 20.0;
+// En deçà de cette fraction du seuil critique, la température ne pénalise pas le score
+const TEMPERATURE_SAFE_RATIO: f64 = 0.6;
+// Fenêtre de mesure de l'activité du swap, et débit (pages/s) auquel le facteur tombe à 0
+const SWAP_WINDOW: std::time::Duration = std::time::Duration::from_millis(500);
+const HEAVY_SWAP_PAGES_PER_SEC: f64 = 1000.0;
+
+// Score de 0 (critique) à 100 (parfait) par facteur
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthFactor {
+    pub name: String, // "cpu", "memory", "disk", "swap" ou "temperature"
+    pub score: f64,
+    pub weight: f64, // Poids normalisé effectivement appliqué
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthScore {
+    pub score: f64,
+    pub factors: Vec<HealthFactor>,
+    // Facteur qui fait perdre le plus de points une fois pondéré
+    pub worst_factor: Option<String>,
+}
+
+fn clamp_score(score: f64) -> f64 {
+    score.clamp(0.0, 100.0)
+}
+
+#[tauri::command]
+pub async fn get_health_score(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+) -> Result<HealthScore, String> {
+    let weights = config.snapshot().health_weights;
+    // Le swap se mesure en va-et-vient de pages : la fenêtre court pendant les autres relevés
+    let swap_started = std::time::Instant::now();
+    let swapped_before = memory_detail::read_swapped_pages();
+    shared.prime().await;
+
+    let mut raw: Vec<(&str, f64, f64, String)> = Vec::new();
+    {
+        let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
+        sys.refresh_cpu();
+        sys.refresh_memory();
+
+        let cpu = sys.global_cpu_info().cpu_usage() as f64;
+        raw.push((
+            "cpu",
+            weights.cpu,
+            clamp_score(100.0 - cpu),
+            format!("{:.0}% CPU used", cpu),
+        ));
+
+        let memory_used = sys.used_memory() as f64 / sys.total_memory().max(1) as f64 * 100.0;
+        raw.push((
+            "memory",
+            weights.memory,
+            clamp_score(100.0 - memory_used),
+            format!("{:.0}% RAM used", memory_used),
+        ));
+    }
+
+    // Le disque le plus plein détermine le facteur
+    let disks = Disks::new_with_refreshed_list();
+    let fullest = disks
+        .iter()
+        .filter(|d| d.total_space() > 0)
+        .map(|d| {
+            (
+                d.available_space() as f64 / d.total_space() as f64 * 100.0,
+                d.mount_point().to_string_lossy().to_string(),
+            )
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    if let Some((free, mount)) = fullest {
+        raw.push((
+            "disk",
+            weights.disk,
+            clamp_score(free / HEALTHY_DISK_FREE_PERCENT * 100.0),
+            format!("{:.0}% free on {}", free, mount),
+        ));
+    }
+
+    // Composant le plus proche de son seuil critique. max() n'est que le pic observé :
+    // un capteur sans seuil critique (ex. k10temp Tctl) est ignoré.
+    let components = Components::new_with_refreshed_list();
+    let hottest = components
+        .iter()
+        .filter(|c| c.temperature().is_finite())
+        .filter_map(|c| {
+            let limit = c.critical().filter(|l| *l > 0.0)?;
+            Some((
+                c.temperature() as f64 / limit as f64,
+                c.label().to_string(),
+                c.temperature(),
+            ))
+        })
+        .max_by(|a, b| a.0.total_cmp(&b.0));
+    if let Some((ratio, label, temperature)) = hottest {
+        let score = (1.0 - ratio) / (1.0 - TEMPERATURE_SAFE_RATIO) * 100.0;
+        raw.push((
+            "temperature",
+            weights.temperature,
+            clamp_score(score),
+            format!("{} at {:.0}°C", label, temperature),
+        ));
+    }
+
+    // Pages échangées avec le swap pendant la fenêtre, qu'il soit presque vide ou plein :
+    // c'est le va-et-vient qui ralentit la machine. Facteur absent hors Linux.
+    if let Some(before) = swapped_before {
+        tokio::time::sleep(SWAP_WINDOW.saturating_sub(swap_started.elapsed())).await;
+        if let Some(after) = memory_detail::read_swapped_pages() {
+            let pages_per_sec = after.saturating_sub(before) as f64 / swap_started.elapsed().as_secs_f64();
+            raw.push((
+                "swap",
+                weights.swap,
+                clamp_score(100.0 - pages_per_sec / HEAVY_SWAP_PAGES_PER_SEC * 100.0),
+                format!("{:.0} pages/s swapped", pages_per_sec),
+            ));
+        }
+    }
+
+    let weight_sum: f64 = raw.iter().map(|(_, weight, _, _)| weight.max(0.0)).sum();
+    if weight_sum <= 0.0 {
+        return Err("Health weights must not all be zero".to_string());
+    }
+
+    let factors: Vec<HealthFactor> = raw
+        .into_iter()
+        .map(|(name, weight, score, detail)| HealthFactor {
+            name: name.to_string(),
+            score,
+            weight: weight.max(0.0) / weight_sum,
+            detail,
+        })
+        .collect();
+
+    let score = factors.iter().map(|f| f.score * f.weight).sum();
+    let worst_factor = factors
+        .iter()
+        .map(|f| ((100.0 - f.score) * f.weight, f))
+        .filter(|(lost, _)| *lost > 0.0)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, f)| f.name.clone());
+
+    Ok(HealthScore {
+        score,
+        factors,
+        worst_factor,
+    })
+}
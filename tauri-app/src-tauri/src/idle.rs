@@ -0,0 +1,65 @@
+// Temps écoulé depuis la dernière entrée clavier/souris de la session interactive
+
+#[cfg(target_os = "windows")]
+fn idle_millis() -> Result<u64, String> {
+    use windows_sys::Win32::System::SystemInformation::GetTickCount;
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    if unsafe { GetLastInputInfo(&mut info) } == 0 {
+        return Err("GetLastInputInfo failed (no interactive session)".to_string());
+    }
+
+    // Les deux compteurs bouclent après ~49 jours, la soustraction doit en tenir compte
+    let now = unsafe { GetTickCount() };
+    Ok(now.wrapping_sub(info.dwTime) as u64)
+}
+
+// Sous Linux, via l'extension XScreenSaver chargée dynamiquement : une machine sans
+// serveur X (serveur, session Wayland pure) obtient simplement une erreur.
+#[cfg(target_os = "linux")]
+fn idle_millis() -> Result<u64, String> {
+    use x11_dl::{xlib::Xlib, xss::Xss};
+
+    let xlib = Xlib::open().map_err(|e| format!("libX11 unavailable: {}", e))?;
+    let xss = Xss::open().map_err(|e| format!("libXss unavailable: {}", e))?;
+
+    unsafe {
+        let display = (xlib.XOpenDisplay)(std::ptr::null());
+        if display.is_null() {
+            return Err("No X display available (headless system)".to_string());
+        }
+
+        let info = (xss.XScreenSaverAllocInfo)();
+        if info.is_null() {
+            (xlib.XCloseDisplay)(display);
+            return Err("XScreenSaverAllocInfo failed".to_string());
+        }
+        let root = (xlib.XDefaultRootWindow)(display);
+        let status = (xss.XScreenSaverQueryInfo)(display, root, info);
+        // c_ulong ne fait pas 64 bits sur toutes les architectures
+        #[allow(clippy::unnecessary_cast)]
+        let idle = (*info).idle as u64;
+
+        (xlib.XFree)(info as *mut _);
+        (xlib.XCloseDisplay)(display);
+
+        if status == 0 {
+            return Err("XScreenSaver extension not supported by the X server".to_string());
+        }
+        Ok(idle)
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn idle_millis() -> Result<u64, String> {
+    Err("Idle time is not supported on this platform".to_string())
+}
+
+#[tauri::command]
+pub fn get_idle_time_secs() -> Result<u64, String> {
+    idle_millis().map(|ms| ms / 1000)
+}
@@ -0,0 +1,152 @@
+// Détection des GPU intégrés (Intel/AMD), invisibles pour NVML. Leur "VRAM" est une
+// portion de la RAM système, on remonte donc surtout la mémoire partagée.
+use crate::gpu::GpuInfo;
+
+const VENDOR_INTEL: u32 = 0x8086;
+const VENDOR_AMD: u32 = 0x1002;
+
+fn integrated(name: String, pci_bus_id: String, shared_memory_total: u64, shared_memory_used: u64) -> GpuInfo {
+    GpuInfo {
+        index: 0,
+        name,
+        pci_bus_id,
+        uuid: String::new(),
+        usage: 0.0,
+        memory_total: 0,
+        memory_used: 0,
+        temperature: None,
+        encoder_util: 0.0,
+        decoder_util: 0.0,
+        is_integrated: true,
+        shared_memory_total,
+        shared_memory_used,
+        power_draw_watts: None,
+        power_limit_watts: None,
+        fan_speed_rpm: None,
+        fan_speed_percent: None,
+    }
+}
+
+// Sous Linux on parcourt /sys/class/drm. Les iGPU Intel sont tous intégrés sauf les Arc
+// (device ID 0x56xx / 0xe2xx) ; côté AMD, un APU n'a qu'une petite réserve de VRAM
+// (≤ 2 Go) et utilise la mémoire GTT, qui est de la RAM système.
+#[cfg(target_os = "linux")]
+pub fn detect() -> Vec<GpuInfo> {
+    use std::fs;
+    use std::path::Path;
+
+    const APU_MAX_VRAM: u64 = 2 * 1024 * 1024 * 1024;
+
+    fn read_hex(path: &Path) -> Option<u32> {
+        let raw = fs::read_to_string(path).ok()?;
+        u32::from_str_radix(raw.trim().trim_start_matches("0x"), 16).ok()
+    }
+
+    fn read_u64(path: &Path) -> Option<u64> {
+        fs::read_to_string(path).ok()?.trim().parse().ok()
+    }
+
+    let Ok(cards) = fs::read_dir("/sys/class/drm") else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = cards
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        // cardN uniquement, pas les connecteurs (card0-HDMI-A-1...)
+        .filter(|n| n.strip_prefix("card").is_some_and(|id| id.chars().all(|c| c.is_ascii_digit())))
+        .collect();
+    names.sort();
+
+    let mut gpus = Vec::new();
+    for card in names {
+        let device = Path::new("/sys/class/drm").join(&card).join("device");
+        let (Some(vendor), Some(device_id)) = (read_hex(&device.join("vendor")), read_hex(&device.join("device"))) else {
+            continue;
+        };
+
+        let is_integrated = match vendor {
+            VENDOR_INTEL => !matches!(device_id >> 8, 0x56 | 0xe2),
+            VENDOR_AMD => read_u64(&device.join("mem_info_vram_total")).is_some_and(|vram| vram <= APU_MAX_VRAM),
+            _ => false,
+        };
+        if !is_integrated {
+            continue;
+        }
+
+        let pci_bus_id = fs::canonicalize(&device)
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .unwrap_or_default();
+
+        gpus.push(integrated(
+            format!(
+                "{} integrated GPU [{:04x}:{:04x}]",
+                if vendor == VENDOR_INTEL { "Intel" } else { "AMD" },
+                vendor,
+                device_id
+            ),
+            pci_bus_id,
+            read_u64(&device.join("mem_info_gtt_total")).unwrap_or(0),
+            read_u64(&device.join("mem_info_gtt_used")).unwrap_or(0),
+        ));
+    }
+
+    gpus
+}
+
+// Sous Windows, DXGI décrit chaque adaptateur avec sa mémoire dédiée et partagée ;
+// un iGPU n'a que quelques centaines de Mo "dédiés" (réservés par le BIOS).
+#[cfg(target_os = "windows")]
+pub fn detect() -> Vec<GpuInfo> {
+    use windows::core::ComInterface;
+    use windows::Win32::Graphics::Dxgi::{
+        CreateDXGIFactory1, IDXGIAdapter3, IDXGIFactory1, DXGI_ADAPTER_DESC1, DXGI_ADAPTER_FLAG_SOFTWARE,
+        DXGI_MEMORY_SEGMENT_GROUP_NON_LOCAL, DXGI_QUERY_VIDEO_MEMORY_INFO,
+    };
+
+    const IGPU_MAX_DEDICATED: usize = 512 * 1024 * 1024;
+
+    let Ok(factory) = (unsafe { CreateDXGIFactory1::<IDXGIFactory1>() }) else {
+        return Vec::new();
+    };
+
+    let mut gpus = Vec::new();
+    let mut i = 0;
+    while let Ok(adapter) = unsafe { factory.EnumAdapters1(i) } {
+        i += 1;
+        let mut desc = DXGI_ADAPTER_DESC1::default();
+        if unsafe { adapter.GetDesc1(&mut desc) }.is_err() {
+            continue;
+        }
+
+        let is_software = desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32 != 0;
+        let is_integrated = matches!(desc.VendorId, VENDOR_INTEL | VENDOR_AMD)
+            && desc.DedicatedVideoMemory <= IGPU_MAX_DEDICATED;
+        if is_software || !is_integrated {
+            continue;
+        }
+
+        let name_len = desc.Description.iter().position(|&c| c == 0).unwrap_or(desc.Description.len());
+        let name = String::from_utf16_lossy(&desc.Description[..name_len]);
+
+        // Mémoire partagée effectivement utilisée (Windows 10+)
+        let mut usage = DXGI_QUERY_VIDEO_MEMORY_INFO::default();
+        let shared_used = adapter
+            .cast::<IDXGIAdapter3>()
+            .ok()
+            .filter(|a3| unsafe { a3.QueryVideoMemoryInfo(0, DXGI_MEMORY_SEGMENT_GROUP_NON_LOCAL, &mut usage) }.is_ok())
+            .map(|_| usage.CurrentUsage)
+            .unwrap_or(0);
+
+        let luid = format!("luid:{:08x}{:08x}", desc.AdapterLuid.HighPart, desc.AdapterLuid.LowPart);
+        gpus.push(integrated(name, luid, desc.SharedSystemMemory as u64, shared_used));
+    }
+
+    gpus
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn detect() -> Vec<GpuInfo> {
+    Vec::new()
+}
@@ -0,0 +1,130 @@
+use chrono::{SecondsFormat, Utc};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use sysinfo::System;
+
+use crate::{collect_processes, refresh_process_list, UserDirectory};
+use crate::config::{ConfigState, GpuHeuristic};
+
+// Au-delà, le fichier courant devient <path>.1 et l'ancien .1 devient .2
+const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
+const ROTATED_FILES: usize = 2;
+
+// Journalisation périodique sur disque, pour garder une trace au-delà d'un redémarrage.
+// Fermer l'émetteur du canal réveille le thread immédiatement au lieu d'attendre la fin
+// de l'intervalle.
+#[derive(Default)]
+pub struct LoggingState {
+    session: Mutex<Option<(Sender<()>, JoinHandle<()>)>>,
+}
+
+impl LoggingState {
+    pub fn stop(&self) {
+        let session = self.session.lock().unwrap().take();
+        if let Some((stop, handle)) = session {
+            drop(stop);
+            let _ = handle.join();
+        }
+    }
+}
+
+fn rotated(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+fn rotate_if_needed(path: &Path) {
+    if fs::metadata(path).map(|m| m.len() < MAX_LOG_SIZE).unwrap_or(true) {
+        return;
+    }
+    for n in (1..ROTATED_FILES).rev() {
+        let _ = fs::rename(rotated(path, n), rotated(path, n + 1));
+    }
+    let _ = fs::rename(path, rotated(path, 1));
+}
+
+fn append_line(path: &Path, line: &str) -> std::io::Result<()> {
+    rotate_if_needed(path);
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+// Ex. "2026-10-14T03:00:00Z cpu=12.3% mem=45.6% top=chrome(23.4%)"
+fn stats_line(sys: &mut System, users: &UserDirectory, gpu_heuristic: &GpuHeuristic) -> String {
+    sys.refresh_cpu();
+    sys.refresh_memory();
+    refresh_process_list(sys);
+
+    // L'usage global de sysinfo est déjà une moyenne sur les cœurs
+    let cpu = sys.global_cpu_info().cpu_usage();
+    let memory = sys.used_memory() as f64 / sys.total_memory().max(1) as f64 * 100.0;
+    let top = collect_processes(sys, users, gpu_heuristic)
+        .into_iter()
+        .max_by(|a, b| a.cpu_usage.total_cmp(&b.cpu_usage))
+        .map(|p| format!("{}({:.1}%)", p.name, p.cpu_usage))
+        .unwrap_or_else(|| "-".to_string());
+
+    format!(
+        "{} cpu={:.1}% mem={:.1}% top={}",
+        Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        cpu,
+        memory,
+        top
+    )
+}
+
+// Remplace une éventuelle session en cours
+#[tauri::command]
+pub fn start_logging(
+    state: tauri::State<'_, LoggingState>,
+    config: tauri::State<'_, ConfigState>,
+    path: String,
+    interval_secs: u64,
+) -> Result<(), String> {
+    if interval_secs == 0 {
+        return Err("interval_secs must be at least 1".to_string());
+    }
+    let path = PathBuf::from(path);
+    // Vérifie dès maintenant que le fichier est accessible en écriture
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Cannot open log file {}: {}", path.display(), e))?;
+
+    state.stop();
+
+    // Réglages figés au démarrage de la session
+    let gpu_heuristic = config.snapshot().gpu_heuristic;
+    let (stop, stopped) = mpsc::channel::<()>();
+    let interval = Duration::from_secs(interval_secs);
+    let handle = std::thread::spawn(move || {
+        let mut sys = System::new();
+        let users = UserDirectory::default();
+        // Premier relevé à vide : sysinfo a besoin de deux mesures pour calculer l'usage CPU
+        sys.refresh_cpu();
+        refresh_process_list(&mut sys);
+        // Toute autre issue que le timeout signifie que stop_logging a fermé le canal
+        while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+            let line = stats_line(&mut sys, &users, &gpu_heuristic);
+            if let Err(e) = append_line(&path, &line) {
+                eprintln!("Stats logging to {} failed: {}", path.display(), e);
+            }
+        }
+    });
+
+    *state.session.lock().map_err(|e| e.to_string())? = Some((stop, handle));
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_logging(state: tauri::State<'_, LoggingState>) -> Result<(), String> {
+    state.stop();
+    Ok(())
+}
@@ -2,9 +2,39 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use serde::{Deserialize, Serialize};
-use sysinfo::{System, Disks, Networks};
+use sysinfo::{CpuRefreshKind, RefreshKind, System, Disks, Networks};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use chrono::{DateTime, Utc};
+use tauri::Manager;
+
+mod checkpoint;
+mod config;
+mod connections;
+mod container;
+mod cpu_cache;
+mod cpu_stat;
+mod delta;
+mod ecc;
+mod gpu;
+mod gpu_processes;
+mod health;
+mod idle;
+mod integrated_gpu;
+mod logging;
+mod memory_detail;
+mod memory_modules;
+mod monitor;
+mod power;
+mod process_net;
+mod raid;
+mod replay;
+mod scan;
+mod services;
+mod storage;
+mod temperature;
+mod traffic;
+mod usb;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SystemInfo {
@@ -14,6 +44,9 @@ pub struct SystemInfo {
     pub hostname: String,
     pub uptime: u64,
     pub boot_time: u64,
+    // Permettent au frontend de localiser les horodatages UTC des stats
+    pub timezone: String, // Nom IANA, ex. "Europe/Paris"
+    pub locale: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -24,6 +57,10 @@ pub struct CpuInfo {
     pub frequency: u64,
     pub cores: usize,
     pub physical_cores: usize,
+    // Tailles totales en Ko (toutes instances), None hors x86
+    pub l1_cache_kb: Option<u64>,
+    pub l2_cache_kb: Option<u64>,
+    pub l3_cache_kb: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -45,6 +82,14 @@ pub struct DiskInfo {
     pub used_space: u64,
     pub usage_percent: f64,
     pub file_system: String,
+    // Débits en octets/s sur DISK_SAMPLE_WINDOW ; None si le disque n'a pas de compteurs d'I/O
+    pub read_bytes_per_sec: Option<u64>,
+    pub write_bytes_per_sec: Option<u64>,
+    pub temperature_celsius: Option<f32>, // SSD NVMe uniquement
+    // Part du temps avec au moins une requête en cours, et nombre moyen de requêtes en
+    // attente : un disque saturé peut afficher un débit faible (accès aléatoires). Linux.
+    pub disk_busy_percent: Option<f64>,
+    pub queue_depth: Option<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -52,21 +97,46 @@ pub struct NetworkInfo {
     pub name: String,
     pub received: u64,
     pub transmitted: u64,
+    pub ip_addresses: Vec<String>, // Notation CIDR, ex. "192.168.1.42/24"
+    // Octets reçus + émis, cumulés depuis le démarrage (contrairement à received/transmitted).
+    // Le noyau ne compte que l'IPv6 par interface : non_ipv6_bytes est le reste du total
+    // de l'interface, soit l'IPv4 plus les en-têtes de liaison, l'ARP et tout trafic non IP.
+    // None si la plateforme ne fournit pas la répartition.
+    pub ipv6_bytes: Option<u64>,
+    pub non_ipv6_bytes: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ProcessInfo {
     pub name: String,
     pub pid: u32,
+    pub parent_pid: Option<u32>,
     pub cpu_usage: f32,
     pub memory: u64,
     pub gpu_usage: f32,
+    // Octets lus/écrits sur disque depuis le relevé précédent
+    pub disk_read_bytes: u64,
+    pub disk_write_bytes: u64,
+    pub start_time: u64, // Secondes depuis l'epoch Unix
+    // Descripteurs ouverts, compté uniquement sur demande (coûteux) et sous Linux
+    pub open_files: Option<usize>,
+    // Nom du propriétaire, ou l'UID brut s'il ne correspond à aucun compte connu
+    pub owner: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessImpact {
+    #[serde(flatten)]
+    pub process: ProcessInfo,
+    pub impact_score: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TemperatureInfo {
     pub component: String,
     pub temperature: f32,
+    // Seuils, jamais des pics observés : ralentissement et arrêt pour les GPU NVIDIA,
+    // seul le critique est connu pour les capteurs système
     pub max_temperature: Option<f32>,
     pub critical_temperature: Option<f32>,
 }
@@ -77,18 +147,174 @@ pub struct AdvancedSystemInfo {
     pub process_count: usize,
     pub total_processes: usize,
     pub users_count: usize,
+    pub open_file_descriptors: Option<u64>, // Tous processus confondus (Linux)
+}
+
+// Sous-systèmes réellement disponibles sur cette machine, pour que l'UI distingue
+// "pas de capteur" d'une lecture en échec
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Capabilities {
+    pub has_temperature: bool,
+    pub has_gpu: bool,
+    pub has_battery: bool,
+    pub has_load_average: bool,
+    pub can_read_per_process_network: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ExtendedRealtimeStats {
     pub cpu_usage: f64,
     pub memory_usage: f64,
-    pub memory_used_gb: f64,
-    pub memory_total_gb: f64,
+    // Seule la paire de l'unité choisie par `unit_base` est renseignée : Gio en base 1024,
+    // Go en base 1000. memory_unit donne le libellé à afficher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_used_gib: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_total_gib: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_used_gb: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_total_gb: Option<f64>,
+    #[serde(default = "binary_gigabyte_label")]
+    pub memory_unit: String,
     pub temperatures: Vec<TemperatureInfo>,
     pub network_activity: HashMap<String, (u64, u64)>, // (received, transmitted)
     pub top_processes: Vec<ProcessInfo>,
     pub timestamp: DateTime<Utc>,
+    // Part de la charge due à l'application elle-même (None avant le second tick du monitoring)
+    pub self_overhead_percent: Option<f32>,
+}
+
+// Les enregistrements de replay antérieurs au réglage d'unité étaient en base 1024
+fn binary_gigabyte_label() -> String {
+    config::UnitBase::default().gigabyte_label().to_string()
+}
+
+impl ExtendedRealtimeStats {
+    // Les anciens enregistrements stockaient des Gio sous memory_used_gb / memory_total_gb
+    pub fn with_legacy_units_fixed(mut self) -> Self {
+        if self.memory_unit == binary_gigabyte_label() && self.memory_used_gib.is_none() {
+            self.memory_used_gib = self.memory_used_gb.take();
+            self.memory_total_gib = self.memory_total_gb.take();
+        }
+        self
+    }
+}
+
+// System partagé par les commandes CPU et processus. Les mesures CPU (globales, par cœur et
+// par processus) sont des deltas entre deux relevés : elles valent 0 tant que prime() n'a
+// pas été appelé. Chaque commande qui s'en sert l'appelle d'abord.
+#[derive(Default)]
+pub struct SharedSystem {
+    sys: std::sync::Mutex<System>,
+    primed: AtomicBool,
+}
+
+impl SharedSystem {
+    // Premier relevé suivi du délai minimal de sysinfo ; sans effet une fois fait
+    async fn prime(&self) {
+        if self.primed.load(Ordering::SeqCst) {
+            return;
+        }
+        {
+            let mut sys = self.sys.lock().unwrap();
+            sys.refresh_cpu();
+            sys.refresh_memory();
+            refresh_process_list(&mut sys);
+        }
+        tokio::time::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL.max(std::time::Duration::from_millis(200))).await;
+        self.sys.lock().unwrap().refresh_cpu();
+        self.primed.store(true, Ordering::SeqCst);
+    }
+}
+
+// Comptes utilisateurs servant à nommer le propriétaire des processus. Relire passwd (ou
+// l'annuaire Windows) à chaque liste serait coûteux : la liste est rafraîchie au plus
+// toutes les USERS_REFRESH_INTERVAL.
+const USERS_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(300);
+
+pub struct UserDirectory(std::sync::Mutex<(sysinfo::Users, std::time::Instant)>);
+
+impl Default for UserDirectory {
+    fn default() -> Self {
+        UserDirectory(std::sync::Mutex::new((sysinfo::Users::new_with_refreshed_list(), std::time::Instant::now())))
+    }
+}
+
+impl UserDirectory {
+    pub fn with_users<R>(&self, f: impl FnOnce(&sysinfo::Users) -> R) -> R {
+        let mut guard = self.0.lock().unwrap();
+        let (users, refreshed_at) = &mut *guard;
+        if refreshed_at.elapsed() >= USERS_REFRESH_INTERVAL {
+            users.refresh_list();
+            *refreshed_at = std::time::Instant::now();
+        }
+        f(users)
+    }
+}
+
+// refresh_processes() de sysinfo ne charge pas le propriétaire : à utiliser avant
+// collect_processes. Le propriétaire d'un pid ne change pas, il n'est lu qu'une fois.
+pub fn refresh_process_list(sys: &mut System) {
+    use sysinfo::{ProcessRefreshKind, UpdateKind};
+
+    sys.refresh_processes_specifics(
+        ProcessRefreshKind::new()
+            .with_memory()
+            .with_cpu()
+            .with_disk_usage()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_user(UpdateKind::OnlyIfNotSet),
+    );
+}
+
+// Normaliser l'usage CPU : diviser par le nombre de cœurs pour obtenir un pourcentage sur 100%.
+// Dans certains conteneurs `cpus()` est vide : on garde au moins un cœur pour ne pas
+// produire des NaN/inf qui feraient paniquer le tri.
+fn normalize_cpu_usage(usage: f32, cpu_count: usize) -> f32 {
+    usage / cpu_count.max(1) as f32
+}
+
+// Construit la liste des processus avec l'usage CPU normalisé et l'estimation GPU
+fn collect_processes(sys: &System, users: &UserDirectory, gpu_heuristic: &config::GpuHeuristic) -> Vec<ProcessInfo> {
+    // Obtenir le nombre de cœurs CPU pour normaliser l'usage
+    let cpu_count = sys.cpus().len();
+
+    users.with_users(|users| {
+        sys.processes()
+            .values()
+            .map(|process| {
+                let normalized_cpu_usage = normalize_cpu_usage(process.cpu_usage(), cpu_count);
+                let pid = process.pid().as_u32();
+
+                // Estimation de l'usage GPU basée sur le nom du processus et l'usage CPU
+                let gpu_usage = gpu_heuristic.estimate(process.name(), pid, normalized_cpu_usage);
+            
+                ProcessInfo {
+                    name: process.name().to_string(),
+                    pid,
+                    parent_pid: process.parent().map(|p| p.as_u32()),
+                    cpu_usage: normalized_cpu_usage,
+                    memory: process.memory(),
+                    gpu_usage,
+                    disk_read_bytes: process.disk_usage().read_bytes,
+                    disk_write_bytes: process.disk_usage().written_bytes,
+                    start_time: process.start_time(),
+                    open_files: None,
+                    owner: owner_name(users, process.user_id()),
+                }
+            })
+            .collect()
+    })
+}
+
+// Un UID sans compte (utilisateur supprimé, conteneur) reste affiché sous forme numérique ;
+// sans UID du tout (processus système sous Windows, accès refusé), "unknown"
+fn owner_name(users: &sysinfo::Users, uid: Option<&sysinfo::Uid>) -> String {
+    match uid {
+        Some(uid) => users.get_user_by_id(uid).map(|user| user.name().to_string()).unwrap_or_else(|| uid.to_string()),
+        None => "unknown".to_string(),
+    }
 }
 
 // Commandes Tauri
@@ -101,15 +327,15 @@ fn get_system_info() -> Result<SystemInfo, String> {
         hostname: System::host_name().unwrap_or_default(),
         uptime: System::uptime(),
         boot_time: System::boot_time(),
+        timezone: iana_time_zone::get_timezone().unwrap_or_else(|_| "UTC".to_string()),
+        locale: sys_locale::get_locale(),
     })
 }
 
 #[tauri::command]
-fn get_cpu_info() -> Result<CpuInfo, String> {
-    let mut sys = System::new_all();
-    sys.refresh_cpu();
-    
-    std::thread::sleep(std::time::Duration::from_millis(200));
+async fn get_cpu_info(shared: tauri::State<'_, SharedSystem>) -> Result<CpuInfo, String> {
+    shared.prime().await;
+    let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
     sys.refresh_cpu();
 
     let cpu = sys.global_cpu_info();
@@ -121,6 +347,9 @@ fn get_cpu_info() -> Result<CpuInfo, String> {
     } else {
         cpus.first().map(|c| c.frequency()).unwrap_or(0)
     };
+
+    let physical_cores = sys.physical_core_count().unwrap_or(0);
+    let caches = cpu_cache::detect(cpus.len(), physical_cores);
     
     Ok(CpuInfo {
         name: cpu.name().to_string(),
@@ -128,10 +357,32 @@ fn get_cpu_info() -> Result<CpuInfo, String> {
         usage: cpu.cpu_usage(),
         frequency,
         cores: cpus.len(),
-        physical_cores: sys.physical_core_count().unwrap_or(0),
+        physical_cores,
+        l1_cache_kb: caches.l1_kb,
+        l2_cache_kb: caches.l2_kb,
+        l3_cache_kb: caches.l3_kb,
     })
 }
 
+// Écart-type de l'usage des cœurs (en points de %) : élevé avec une moyenne modérée,
+// la charge est concentrée sur quelques cœurs (threads épinglés, NUMA...)
+#[tauri::command]
+async fn get_core_imbalance(shared: tauri::State<'_, SharedSystem>) -> Result<f64, String> {
+    shared.prime().await;
+    let usages: Vec<f64> = {
+        let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
+        sys.refresh_cpu();
+        sys.cpus().iter().map(|cpu| cpu.cpu_usage() as f64).collect()
+    };
+    if usages.is_empty() {
+        return Err("No per-core CPU usage available".to_string());
+    }
+
+    let mean = usages.iter().sum::<f64>() / usages.len() as f64;
+    let variance = usages.iter().map(|u| (u - mean).powi(2)).sum::<f64>() / usages.len() as f64;
+    Ok(variance.sqrt())
+}
+
 #[tauri::command]
 fn get_memory_info() -> Result<MemoryInfo, String> {
     let mut sys = System::new_all();
@@ -152,60 +403,288 @@ fn get_memory_info() -> Result<MemoryInfo, String> {
     })
 }
 
-#[tauri::command]
-fn get_disk_info() -> Result<Vec<DiskInfo>, String> {
+fn disk_info_of(disk: &sysinfo::Disk) -> DiskInfo {
+    let total = disk.total_space();
+    let available = disk.available_space();
+    let used = total - available;
+    let usage_percent = if total > 0 {
+        (used as f64 / total as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    DiskInfo {
+        name: disk.name().to_string_lossy().to_string(),
+        mount_point: disk.mount_point().to_string_lossy().to_string(),
+        total_space: total,
+        available_space: available,
+        used_space: used,
+        usage_percent,
+        file_system: disk.file_system().to_string_lossy().to_string(),
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
+        temperature_celsius: storage::nvme_temperature(disk),
+        disk_busy_percent: None,
+        queue_depth: None,
+    }
+}
+
+// Fenêtre de mesure de l'activité des disques : les compteurs d'I/O sont cumulés
+const DISK_SAMPLE_WINDOW: std::time::Duration = std::time::Duration::from_millis(500);
+
+// Disques avec débits, taux d'occupation et file d'attente mesurés sur DISK_SAMPLE_WINDOW.
+// Un disque sans compteurs (montage réseau, plateforme non gérée) garde ces champs à None.
+async fn sample_disks() -> Vec<DiskInfo> {
     let disks = Disks::new_with_refreshed_list();
+    let before = storage::io_counters(&disks);
+    tokio::time::sleep(DISK_SAMPLE_WINDOW).await;
+    let after = storage::io_counters(&disks);
 
-    let disk_info = disks
+    disks
         .iter()
         .map(|disk| {
-            let total = disk.total_space();
-            let available = disk.available_space();
-            let used = total - available;
-            let usage_percent = if total > 0 {
-                (used as f64 / total as f64) * 100.0
-            } else {
-                0.0
+            let mut info = disk_info_of(disk);
+            let (Some(before), Some(after)) = (before.get(&info.mount_point), after.get(&info.mount_point)) else {
+                return info;
             };
+            let per_sec = |delta: u64| (delta as f64 / DISK_SAMPLE_WINDOW.as_secs_f64()) as u64;
+            info.read_bytes_per_sec = Some(per_sec(after.read_bytes.saturating_sub(before.read_bytes)));
+            info.write_bytes_per_sec = Some(per_sec(after.written_bytes.saturating_sub(before.written_bytes)));
 
-            DiskInfo {
-                name: disk.name().to_string_lossy().to_string(),
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
-                total_space: total,
-                available_space: available,
-                used_space: used,
-                usage_percent,
-                file_system: disk.file_system().to_string_lossy().to_string(),
-            }
+            // Millisecondes d'I/O rapportées aux millisecondes écoulées
+            let over_window = |before: Option<u64>, after: Option<u64>| {
+                Some(after?.saturating_sub(before?) as f64 / DISK_SAMPLE_WINDOW.as_millis() as f64)
+            };
+            info.disk_busy_percent =
+                over_window(before.io_time_ms, after.io_time_ms).map(|ratio| (ratio * 100.0).min(100.0));
+            info.queue_depth = over_window(before.weighted_io_time_ms, after.weighted_io_time_ms);
+            info
         })
-        .collect();
+        .collect()
+}
 
-    Ok(disk_info)
+#[tauri::command]
+async fn get_disk_info() -> Result<Vec<DiskInfo>, String> {
+    Ok(sample_disks().await)
+}
+
+// Disque dont le débit lecture + écriture est le plus élevé sur DISK_SAMPLE_WINDOW.
+// rank_by = "busy" classe plutôt par taux d'occupation (un disque saturé d'accès 4K aléatoires
+// a un débit faible), le débit départageant les ex aequo. Sans occupation ni débit, un
+// disque est considéré inactif.
+#[tauri::command]
+async fn get_busiest_disk(rank_by: Option<String>) -> Result<Option<DiskInfo>, String> {
+    let by_busy = match rank_by.as_deref().unwrap_or("throughput") {
+        "throughput" => false,
+        "busy" => true,
+        other => return Err(format!("Unknown rank_by value: {} (expected throughput or busy)", other)),
+    };
+    let busy = |info: &DiskInfo| info.disk_busy_percent.unwrap_or(0.0);
+    let throughput = |info: &DiskInfo| info.read_bytes_per_sec.unwrap_or(0) + info.write_bytes_per_sec.unwrap_or(0);
+
+    let disks = sample_disks()
+        .await
+        .into_iter()
+        .filter(|info| busy(info) > 0.0 || throughput(info) > 0);
+    let busiest = if by_busy {
+        disks.max_by(|a, b| busy(a).total_cmp(&busy(b)).then(throughput(a).cmp(&throughput(b))))
+    } else {
+        disks.filter(|info| throughput(info) > 0).max_by_key(throughput)
+    };
+
+    Ok(busiest)
+}
+
+// Adresses IPv4 et IPv6 de chaque interface, avec la longueur de préfixe.
+// sysinfo 0.30 n'expose pas les adresses IP, on passe donc par if-addrs.
+fn interface_addresses() -> HashMap<String, Vec<String>> {
+    let mut addresses: HashMap<String, Vec<String>> = HashMap::new();
+
+    for iface in if_addrs::get_if_addrs().unwrap_or_default() {
+        let cidr = match &iface.addr {
+            if_addrs::IfAddr::V4(v4) => format!("{}/{}", v4.ip, v4.prefixlen),
+            if_addrs::IfAddr::V6(v6) => format!("{}/{}", v6.ip, v6.prefixlen),
+        };
+        addresses.entry(iface.name).or_default().push(cidr);
+    }
+
+    addresses
+}
+
+// Octets IPv6 entrants + sortants de l'interface, d'après /proc/net/dev_snmp6/<iface>
+// (absent si IPv6 est désactivé sur l'interface)
+#[cfg(target_os = "linux")]
+fn interface_ipv6_bytes(name: &str) -> Option<u64> {
+    let stats = std::fs::read_to_string(format!("/proc/net/dev_snmp6/{}", name)).ok()?;
+    let counter = |key: &str| {
+        stats.lines().find_map(|line| {
+            let (field, value) = line.split_once(char::is_whitespace)?;
+            (field == key).then(|| value.trim().parse::<u64>().ok()).flatten()
+        })
+    };
+    Some(counter("Ip6InOctets")? + counter("Ip6OutOctets")?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn interface_ipv6_bytes(_name: &str) -> Option<u64> {
+    None
 }
 
 #[tauri::command]
 fn get_network_info() -> Result<Vec<NetworkInfo>, String> {
     let networks = Networks::new_with_refreshed_list();
+    let mut addresses = interface_addresses();
 
     let network_info = networks
         .iter()
-        .map(|(name, network)| NetworkInfo {
-            name: name.clone(),
-            received: network.received(),
-            transmitted: network.transmitted(),
+        .map(|(name, network)| {
+            let ipv6_bytes = interface_ipv6_bytes(name);
+            let non_ipv6_bytes = ipv6_bytes
+                .map(|v6| (network.total_received() + network.total_transmitted()).saturating_sub(v6));
+            NetworkInfo {
+                name: name.clone(),
+                received: network.received(),
+                transmitted: network.transmitted(),
+                ip_addresses: addresses.remove(name).unwrap_or_default(),
+                ipv6_bytes,
+                non_ipv6_bytes,
+            }
         })
         .collect();
 
     Ok(network_info)
 }
 
+// Interface portant la route par défaut (IPv4, sinon IPv6) : la moins coûteuse (metric)
+// parmi les routes actives vers 0.0.0.0/0 ou ::/0
+#[cfg(target_os = "linux")]
+fn default_route_interface() -> Result<Option<String>, String> {
+    const RTF_UP: u32 = 0x1;
+
+    // Iface Destination Gateway Flags RefCnt Use Metric Mask..., valeurs en hexadécimal
+    let routes = std::fs::read_to_string("/proc/net/route").map_err(|e| format!("Cannot read /proc/net/route: {}", e))?;
+    let best_v4 = routes
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let flags = u32::from_str_radix(fields.get(3)?, 16).ok()?;
+            let is_default = *fields.get(1)? == "00000000" && *fields.get(7)? == "00000000";
+            let metric: u32 = fields.get(6)?.parse().ok()?;
+            (is_default && flags & RTF_UP != 0).then(|| (metric, fields[0].to_string()))
+        })
+        .min();
+    if let Some((_, iface)) = best_v4 {
+        return Ok(Some(iface));
+    }
+
+    // Destination Préfixe Source Préfixe NextHop Metric RefCnt Use Flags Iface
+    let routes_v6 = std::fs::read_to_string("/proc/net/ipv6_route").unwrap_or_default();
+    Ok(routes_v6
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let is_default = fields.first()?.chars().all(|c| c == '0') && *fields.get(1)? == "00";
+            let metric = u32::from_str_radix(fields.get(5)?, 16).ok()?;
+            let flags = u32::from_str_radix(fields.get(8)?, 16).ok()?;
+            let iface = fields.get(9)?;
+            (is_default && flags & RTF_UP != 0 && *iface != "lo").then(|| (metric, iface.to_string()))
+        })
+        .min()
+        .map(|(_, iface)| iface))
+}
+
+// GetBestInterface vers une adresse publique, puis nom de l'interface via son index
+#[cfg(target_os = "windows")]
+fn default_route_interface() -> Result<Option<String>, String> {
+    use windows_sys::Win32::Foundation::NO_ERROR;
+    use windows_sys::Win32::NetworkManagement::IpHelper::GetBestInterface;
+
+    // 8.8.8.8, en ordre réseau ; aucun paquet n'est envoyé
+    let destination = u32::from_ne_bytes([8, 8, 8, 8]);
+    let mut index = 0u32;
+    if unsafe { GetBestInterface(destination, &mut index) } != NO_ERROR {
+        // Pas de route vers l'extérieur (machine hors ligne)
+        return Ok(None);
+    }
+
+    let interfaces = if_addrs::get_if_addrs().map_err(|e| e.to_string())?;
+    Ok(interfaces.into_iter().find(|iface| iface.index == Some(index)).map(|iface| iface.name))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn default_route_interface() -> Result<Option<String>, String> {
+    Err("Default route lookup is not supported on this platform".to_string())
+}
+
+// Ok(None) sans route par défaut (machine hors ligne)
 #[tauri::command]
-async fn get_real_time_stats() -> Result<HashMap<String, f64>, String> {
-    let mut sys = System::new_all();
-    sys.refresh_all();
-    
-    std::thread::sleep(std::time::Duration::from_millis(200));
+fn get_default_interface() -> Result<Option<String>, String> {
+    default_route_interface()
+}
+
+// Interfaces retenues par défaut pour la bande passante : on écarte loopback, ponts et
+// interfaces virtuelles (conteneurs, VM, VPN) qui compteraient le trafic en double.
+#[cfg(target_os = "linux")]
+fn is_physical_interface(name: &str) -> bool {
+    std::path::Path::new("/sys/class/net").join(name).join("device").exists()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_physical_interface(name: &str) -> bool {
+    let name = name.to_lowercase();
+    !["loopback", "vethernet", "virtualbox", "vmware", "tap", "tun", "wsl", "docker"]
+        .iter()
+        .any(|virtual_name| name.contains(virtual_name))
+}
+
+// Débits descendant et montant cumulés, en bits par seconde
+#[tauri::command]
+async fn get_bandwidth(interfaces: Option<Vec<String>>) -> Result<(f64, f64), String> {
+    const WINDOW: std::time::Duration = std::time::Duration::from_millis(500);
+
+    let mut networks = Networks::new_with_refreshed_list();
+    if let Some(names) = &interfaces {
+        if let Some(unknown) = names.iter().find(|name| !networks.contains_key(*name)) {
+            return Err(format!("Unknown network interface: {}", unknown));
+        }
+    }
+
+    tokio::time::sleep(WINDOW).await;
+    // received()/transmitted() donnent ensuite les octets échangés depuis le relevé précédent
+    networks.refresh();
+
+    let (received, transmitted) = networks
+        .iter()
+        .filter(|(name, _)| match &interfaces {
+            Some(names) => names.contains(name),
+            None => is_physical_interface(name),
+        })
+        .fold((0u64, 0u64), |(rx, tx), (_, network)| (rx + network.received(), tx + network.transmitted()));
+
+    let to_bps = |bytes: u64| bytes as f64 * 8.0 / WINDOW.as_secs_f64();
+    Ok((to_bps(received), to_bps(transmitted)))
+}
+
+// Le frontend doit attendre la fin de prime() avant d'afficher des mesures : avant,
+// l'usage CPU renvoyé par les commandes est peu fiable (souvent 0)
+#[tauri::command]
+async fn prime(shared: tauri::State<'_, SharedSystem>) -> Result<(), String> {
+    shared.prime().await;
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_real_time_stats(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+) -> Result<HashMap<String, f64>, String> {
+    let unit_base = config.snapshot().unit_base;
+    // Le relevé précédent (prime ou appel antérieur) sert de référence pour l'usage CPU
+    shared.prime().await;
+    let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
     sys.refresh_cpu();
+    sys.refresh_memory();
 
     let mut stats = HashMap::new();
     
@@ -216,74 +695,391 @@ async fn get_real_time_stats() -> Result<HashMap<String, f64>, String> {
     let memory_percent = (sys.used_memory() as f64 / sys.total_memory() as f64) * 100.0;
     stats.insert("memory_usage".to_string(), memory_percent);
     
-    // Memory in GB
-    stats.insert("memory_used_gb".to_string(), sys.used_memory() as f64 / 1_024_f64.powi(3));
-    stats.insert("memory_total_gb".to_string(), sys.total_memory() as f64 / 1_024_f64.powi(3));
+    // memory_used_gib/memory_total_gib en base 1024, memory_used_gb/memory_total_gb en base 1000
+    let suffix = unit_base.gigabyte_label().to_lowercase();
+    stats.insert(format!("memory_used_{}", suffix), unit_base.gigabytes(sys.used_memory()));
+    stats.insert(format!("memory_total_{}", suffix), unit_base.gigabytes(sys.total_memory()));
 
     Ok(stats)
 }
 
 #[tauri::command]
 fn get_temperatures() -> Result<Vec<TemperatureInfo>, String> {
-    // Températures simulées car sysinfo 0.30 n'a plus components()
-    Ok(vec![
-        TemperatureInfo {
-            component: "CPU Package".to_string(),
-            temperature: 45.0, // Valeur simulée
-            max_temperature: Some(100.0),
-            critical_temperature: Some(105.0),
-        },
-        TemperatureInfo {
-            component: "System".to_string(),
-            temperature: 35.0, // Valeur simulée
-            max_temperature: Some(80.0),
-            critical_temperature: Some(90.0),
+    // La liste des CPU (sans mesure) suffit à identifier le fabricant
+    let sys = System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::new()));
+    Ok(temperature::read_temperatures(&sys))
+}
+
+// Indicateur thermique unique : capteurs système et GPU NVIDIA confondus
+#[tauri::command]
+fn get_hottest_component() -> Result<Option<TemperatureInfo>, String> {
+    let sys = System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::new()));
+    let mut temperatures = temperature::read_temperatures(&sys);
+    temperatures.extend(gpu::nvidia_temperatures());
+    Ok(temperature::hottest_component(temperatures))
+}
+
+// Indique si la machine est sur secteur. Une machine sans batterie (PC fixe)
+// est considérée sur secteur : la commande renvoie alors `true`.
+#[tauri::command]
+fn is_on_ac_power() -> Result<bool, String> {
+    let manager = battery::Manager::new().map_err(|e| e.to_string())?;
+    let batteries = manager.batteries().map_err(|e| e.to_string())?;
+
+    for battery in batteries.flatten() {
+        if battery.state() == battery::State::Discharging {
+            return Ok(false);
         }
-    ])
+    }
+
+    Ok(true)
+}
+
+// /proc/sys/fs/file-nr : "alloués  inutilisés  maximum"
+#[cfg(target_os = "linux")]
+fn system_open_file_descriptors() -> Option<u64> {
+    let content = std::fs::read_to_string("/proc/sys/fs/file-nr").ok()?;
+    let mut fields = content.split_whitespace().map(|f| f.parse::<u64>().ok());
+    let allocated = fields.next()??;
+    let unused = fields.next()??;
+    Some(allocated.saturating_sub(unused))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn system_open_file_descriptors() -> Option<u64> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn open_files_of(pid: u32) -> Option<usize> {
+    std::fs::read_dir(format!("/proc/{}/fd", pid)).ok().map(|fds| fds.count())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_files_of(_pid: u32) -> Option<usize> {
+    None
 }
 
 #[tauri::command]
-fn get_top_processes() -> Result<Vec<ProcessInfo>, String> {
-    let mut sys = System::new_all();
-    sys.refresh_processes();
+async fn get_top_processes(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    count_open_files: Option<bool>,
+    sort_by: Option<String>,
+) -> Result<Vec<ProcessInfo>, String> {
+    shared.prime().await;
+    let mut processes = {
+        let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
+        sys.refresh_cpu();
+        refresh_process_list(&mut sys);
+        collect_processes(&sys, &users, &config.snapshot().gpu_heuristic)
+    };
+
+    // Par défaut, trier par utilisation CPU décroissante ; "newest" aide à relier un pic
+    // à un processus qui vient d'apparaître
+    match sort_by.as_deref().unwrap_or("cpu") {
+        "cpu" => processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap()),
+        "memory" => processes.sort_by_key(|p| std::cmp::Reverse(p.memory)),
+        "newest" => processes.sort_by_key(|p| std::cmp::Reverse(p.start_time)),
+        "oldest" => processes.sort_by_key(|p| p.start_time),
+        other => {
+            return Err(format!(
+                "Unknown sort_by value: {} (expected cpu, memory, newest or oldest)",
+                other
+            ))
+        }
+    }
     
-    // Obtenir le nombre de cœurs CPU pour normaliser l'usage
-    let cpu_count = sys.cpus().len() as f32;
+    // Retourner les 15 premiers pour la fenêtre des processus
+    processes.truncate(15);
 
-    let mut processes: Vec<ProcessInfo> = sys.processes()
+    // Le comptage des descripteurs parcourt /proc/<pid>/fd : désactivé par défaut,
+    // et limité aux processus retournés
+    if count_open_files.unwrap_or(false) {
+        for process in &mut processes {
+            process.open_files = open_files_of(process.pid);
+        }
+    }
+
+    Ok(processes)
+}
+
+// Processus zombies : terminés mais jamais "récoltés" par leur parent (parent_pid),
+// qui est le vrai coupable à identifier
+#[tauri::command]
+fn get_zombie_processes(
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+) -> Result<Vec<ProcessInfo>, String> {
+    let mut sys = System::new();
+    refresh_process_list(&mut sys);
+
+    let zombies: std::collections::HashSet<u32> = sys
+        .processes()
         .values()
-        .map(|process| {
-            // Normaliser l'usage CPU : diviser par le nombre de cœurs pour obtenir un pourcentage sur 100%
-            let normalized_cpu_usage = process.cpu_usage() / cpu_count;
-            
-            // Simulation de l'usage GPU basée sur le nom du processus et l'usage CPU
-            let gpu_usage = match process.name() {
-                name if name.contains("chrome") || name.contains("firefox") || name.contains("edge") => 
-                    (normalized_cpu_usage * 0.3).min(15.0), // Navigateurs utilisent un peu de GPU
-                name if name.contains("game") || name.contains("unity") || name.contains("unreal") => 
-                    (normalized_cpu_usage * 2.0).min(85.0), // Jeux utilisent beaucoup de GPU
-                name if name.contains("nvidia") || name.contains("amd") || name.contains("gpu") => 
-                    (normalized_cpu_usage * 1.5).min(25.0), // Processus GPU
-                name if name.contains("WSIMC") => 
-                    (normalized_cpu_usage * 0.1).min(5.0), // Notre app utilise peu de GPU
-                _ => (normalized_cpu_usage * 0.05).min(3.0), // Processus normaux utilisent très peu de GPU
-            };
-            
-            ProcessInfo {
-                name: process.name().to_string(),
-                pid: process.pid().as_u32(),
-                cpu_usage: normalized_cpu_usage,
-                memory: process.memory(),
-                gpu_usage,
+        .filter(|p| p.status() == sysinfo::ProcessStatus::Zombie)
+        .map(|p| p.pid().as_u32())
+        .collect();
+
+    let mut processes: Vec<ProcessInfo> = collect_processes(&sys, &users, &config.snapshot().gpu_heuristic)
+        .into_iter()
+        .filter(|p| zombies.contains(&p.pid))
+        .collect();
+    processes.sort_by_key(|p| (p.parent_pid, p.pid));
+
+    Ok(processes)
+}
+
+// Processus orphelins : leur parent a disparu (Windows garde le pid du parent, qui peut aussi
+// avoir été réattribué à un processus plus récent). Sous Unix un orphelin est rattaché à init
+// (pid 1) et ne se distingue alors plus d'un démon ; include_init_children ajoute les enfants
+// d'init qui ne sont pas leaders de leur session, les démons et services l'étant en général.
+// Les zombies sont listés à part par get_zombie_processes.
+#[tauri::command]
+fn get_orphaned_processes(
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    include_init_children: Option<bool>,
+) -> Result<Vec<ProcessInfo>, String> {
+    let include_init_children = include_init_children.unwrap_or(false);
+    let mut sys = System::new();
+    refresh_process_list(&mut sys);
+
+    let orphans: std::collections::HashSet<u32> = sys
+        .processes()
+        .values()
+        // Les threads (listés comme processus sous Linux) ont pour parent leur processus
+        .filter(|p| p.thread_kind().is_none())
+        .filter(|p| match p.parent() {
+            // Pid 1 lui-même, processus système Windows : pas de parent par construction
+            None => false,
+            Some(parent) if cfg!(unix) && parent.as_u32() == 1 => {
+                include_init_children && p.session_id().is_some_and(|session| session != p.pid())
             }
+            Some(parent) => sys.process(parent).is_none_or(|parent| parent.start_time() > p.start_time()),
         })
+        .map(|p| p.pid().as_u32())
+        .collect();
+
+    let mut processes: Vec<ProcessInfo> = collect_processes(&sys, &users, &config.snapshot().gpu_heuristic)
+        .into_iter()
+        .filter(|p| orphans.contains(&p.pid))
         .collect();
+    processes.sort_by_key(|p| (p.parent_pid, p.pid));
 
-    // Trier par utilisation CPU décroissante
+    Ok(processes)
+}
+
+// Processus d'un utilisateur donné (nom de compte, ou UID numérique), triés par usage CPU
+#[tauri::command]
+async fn get_processes_by_user(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    username: String,
+) -> Result<Vec<ProcessInfo>, String> {
+    shared.prime().await;
+    let processes = {
+        let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
+        sys.refresh_cpu();
+        refresh_process_list(&mut sys);
+        collect_processes(&sys, &users, &config.snapshot().gpu_heuristic)
+    };
+
+    let mut processes: Vec<ProcessInfo> = processes
+        .into_iter()
+        .filter(|p| p.owner == username)
+        .collect();
     processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap());
-    
-    // Retourner les 15 premiers pour la fenêtre des processus
-    Ok(processes.into_iter().take(15).collect())
+
+    Ok(processes)
+}
+
+// Processus ayant le plus écrit sur disque pendant une fenêtre d'une demi-seconde
+#[tauri::command]
+async fn get_top_disk_writer(
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+) -> Result<Option<ProcessInfo>, String> {
+    let gpu_heuristic = config.snapshot().gpu_heuristic;
+    let mut sys = System::new();
+    refresh_process_list(&mut sys);
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    refresh_process_list(&mut sys);
+
+    Ok(collect_processes(&sys, &users, &gpu_heuristic)
+        .into_iter()
+        .filter(|p| p.disk_write_bytes > 0)
+        .max_by_key(|p| p.disk_write_bytes))
+}
+
+// Processus dépassant un seuil CPU (en % normalisé) et/ou mémoire (en octets).
+// Par défaut un seul seuil dépassé suffit (OU) ; `all` exige les deux (ET).
+#[tauri::command]
+async fn get_processes_above(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    cpu_percent: Option<f32>,
+    memory_bytes: Option<u64>,
+    all: Option<bool>,
+) -> Result<Vec<ProcessInfo>, String> {
+    if cpu_percent.is_none() && memory_bytes.is_none() {
+        return Err("At least one threshold (cpu_percent or memory_bytes) is required".to_string());
+    }
+
+    // L'usage CPU par processus se mesure depuis le relevé précédent du System partagé
+    shared.prime().await;
+    let processes = {
+        let mut sys = shared.sys.lock().map_err(|e| e.to_string())?;
+        sys.refresh_cpu();
+        refresh_process_list(&mut sys);
+        collect_processes(&sys, &users, &config.snapshot().gpu_heuristic)
+    };
+
+    let all = all.unwrap_or(false);
+    let mut processes: Vec<ProcessInfo> = processes
+        .into_iter()
+        .filter(|p| {
+            let checks = [
+                cpu_percent.map(|threshold| p.cpu_usage > threshold),
+                memory_bytes.map(|threshold| p.memory > threshold),
+            ];
+            let mut active = checks.iter().flatten();
+            if all {
+                active.all(|&exceeded| exceeded)
+            } else {
+                active.any(|&exceeded| exceeded)
+            }
+        })
+        .collect();
+
+    processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap());
+
+    Ok(processes)
+}
+
+// Vrai si l'OS refuse la lecture de l'environnement (processus d'un autre utilisateur) :
+// sysinfo renvoie alors simplement une liste vide, indiscernable d'un environnement vide.
+#[cfg(target_os = "linux")]
+fn environment_forbidden(pid: u32, _process: &sysinfo::Process) -> bool {
+    std::fs::read(format!("/proc/{}/environ", pid))
+        .is_err_and(|e| e.kind() == std::io::ErrorKind::PermissionDenied)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn environment_forbidden(_pid: u32, process: &sysinfo::Process) -> bool {
+    let mut sys = System::new();
+    let own_user = sysinfo::get_current_pid().ok().and_then(|own| {
+        sys.refresh_process_specifics(own, sysinfo::ProcessRefreshKind::new().with_user(sysinfo::UpdateKind::Always));
+        sys.process(own).and_then(|p| p.user_id().cloned())
+    });
+    matches!((process.user_id(), own_user), (Some(owner), Some(own)) if *owner != own)
+}
+
+// Variables masquées sauf si reveal est demandé
+fn is_sensitive_variable(key: &str) -> bool {
+    let key = key.to_uppercase();
+    ["TOKEN", "SECRET", "PASSWORD", "KEY"].iter().any(|word| key.contains(word))
+}
+
+#[tauri::command]
+fn get_process_environment(pid: u32, reveal: Option<bool>) -> Result<Vec<(String, String)>, String> {
+    use sysinfo::{Pid, ProcessRefreshKind, UpdateKind};
+
+    let mut sys = System::new();
+    let refresh = ProcessRefreshKind::new()
+        .with_environ(UpdateKind::Always)
+        .with_user(UpdateKind::Always);
+    if !sys.refresh_process_specifics(Pid::from_u32(pid), refresh) {
+        return Err(format!("Process {} not found", pid));
+    }
+    let process = sys.process(Pid::from_u32(pid)).ok_or_else(|| format!("Process {} not found", pid))?;
+
+    if process.environ().is_empty() && environment_forbidden(pid, process) {
+        return Err(format!("Permission denied: cannot read the environment of process {}", pid));
+    }
+
+    let reveal = reveal.unwrap_or(false);
+    let mut variables: Vec<(String, String)> = process
+        .environ()
+        .iter()
+        .map(|entry| {
+            let (key, value) = entry.split_once('=').unwrap_or((entry.as_str(), ""));
+            let value = if !reveal && is_sensitive_variable(key) { "***" } else { value };
+            (key.to_string(), value.to_string())
+        })
+        .collect();
+    variables.sort();
+
+    Ok(variables)
+}
+
+// Classement "gros consommateurs" : CPU normalisé, mémoire en % de la RAM totale et
+// I/O disque relative au processus le plus actif, pondérés selon la configuration.
+#[tauri::command]
+async fn get_high_impact_processes(
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    limit: usize,
+) -> Result<Vec<ProcessImpact>, String> {
+    let config = config.snapshot();
+    let weights = &config.impact_weights;
+    let weight_sum = weights.cpu + weights.memory + weights.disk;
+    if weight_sum <= 0.0 {
+        return Err("Impact weights must not all be zero".to_string());
+    }
+
+    let mut sys = System::new_all();
+    refresh_process_list(&mut sys);
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    refresh_process_list(&mut sys);
+
+    let total_memory = sys.total_memory().max(1) as f64;
+    let disk_io = |pid: u32| {
+        sys.process(sysinfo::Pid::from_u32(pid))
+            .map(|p| {
+                let usage = p.disk_usage();
+                usage.read_bytes + usage.written_bytes
+            })
+            .unwrap_or(0)
+    };
+    let max_disk_io = sys
+        .processes()
+        .values()
+        .map(|p| p.disk_usage().read_bytes + p.disk_usage().written_bytes)
+        .max()
+        .unwrap_or(0)
+        .max(1) as f64;
+
+    let mut processes: Vec<ProcessImpact> = collect_processes(&sys, &users, &config.gpu_heuristic)
+        .into_iter()
+        .map(|process| {
+            let memory_percent = process.memory as f64 / total_memory * 100.0;
+            let disk_percent = disk_io(process.pid) as f64 / max_disk_io * 100.0;
+            let impact_score = (weights.cpu * process.cpu_usage as f64
+                + weights.memory * memory_percent
+                + weights.disk * disk_percent)
+                / weight_sum;
+            ProcessImpact { process, impact_score }
+        })
+        .collect();
+
+    processes.sort_by(|a, b| b.impact_score.partial_cmp(&a.impact_score).unwrap());
+    processes.truncate(limit);
+
+    Ok(processes)
+}
+
+// Charge moyenne sur 1, 5 et 15 minutes. Windows n'a pas de load average : sysinfo y
+// renvoie toujours des zéros, on n'en renvoie donc aucune.
+fn load_average() -> Option<[f64; 3]> {
+    if cfg!(target_os = "windows") {
+        return None;
+    }
+    let load = System::load_average();
+    Some([load.one, load.five, load.fifteen])
 }
 
 #[tauri::command]
@@ -292,37 +1088,64 @@ fn get_advanced_system_info() -> Result<AdvancedSystemInfo, String> {
     sys.refresh_all();
 
     Ok(AdvancedSystemInfo {
-        load_average: vec![0.0, 0.0, 0.0], // load_average n'est plus disponible
+        load_average: load_average().map(Vec::from).unwrap_or_default(), // Vide sous Windows
         process_count: sys.processes().len(),
         total_processes: sys.processes().len(),
         users_count: 1, // users() n'est plus disponible
+        open_file_descriptors: system_open_file_descriptors(),
     })
 }
 
 #[tauri::command]
-async fn get_extended_realtime_stats() -> Result<ExtendedRealtimeStats, String> {
-    let mut sys = System::new_all();
-    sys.refresh_all();
-    
-    std::thread::sleep(std::time::Duration::from_millis(200));
+fn get_capabilities() -> Result<Capabilities, String> {
+    let has_battery = battery::Manager::new()
+        .and_then(|manager| manager.batteries())
+        .map(|mut batteries| batteries.any(|b| b.is_ok()))
+        .unwrap_or(false);
+
+    let has_load_average = load_average().is_some_and(|load| load.iter().any(|l| *l > 0.0));
+
+    Ok(Capabilities {
+        has_temperature: !sysinfo::Components::new_with_refreshed_list().is_empty(),
+        has_gpu: !gpu::all_gpus().is_empty(),
+        has_battery,
+        has_load_average,
+        can_read_per_process_network: process_net::is_supported(),
+    })
+}
+
+// En mode replay, les échantillons enregistrés remplacent les mesures réelles
+#[tauri::command]
+async fn get_extended_realtime_stats(
+    shared: tauri::State<'_, SharedSystem>,
+    config: tauri::State<'_, config::ConfigState>,
+    users: tauri::State<'_, UserDirectory>,
+    replay: tauri::State<'_, replay::ReplayState>,
+    monitor: tauri::State<'_, monitor::MonitorState>,
+) -> Result<ExtendedRealtimeStats, String> {
+    if let Some(sample) = replay.next_sample() {
+        return Ok(sample);
+    }
+
+    let self_overhead_percent = *monitor.shared.self_overhead_percent.lock().map_err(|e| e.to_string())?;
+    let stats = collect_extended_stats(&shared, &config.snapshot(), &users, self_overhead_percent).await;
+    replay.record(&stats)?;
+    Ok(stats)
+}
+
+async fn collect_extended_stats(
+    shared: &SharedSystem,
+    config: &config::AppConfig,
+    users: &UserDirectory,
+    self_overhead_percent: Option<f32>,
+) -> ExtendedRealtimeStats {
+    shared.prime().await;
+    let mut sys = shared.sys.lock().unwrap();
     sys.refresh_cpu();
-    sys.refresh_processes();
-
-    // Températures simulées
-    let temperatures: Vec<TemperatureInfo> = vec![
-        TemperatureInfo {
-            component: "CPU Package".to_string(),
-            temperature: 45.0 + (sys.global_cpu_info().cpu_usage() * 0.5), // Simulée basée sur l'usage CPU
-            max_temperature: Some(100.0),
-            critical_temperature: Some(105.0),
-        },
-        TemperatureInfo {
-            component: "System".to_string(),
-            temperature: 35.0 + (sys.global_cpu_info().cpu_usage() * 0.3),
-            max_temperature: Some(80.0),
-            critical_temperature: Some(90.0),
-        }
-    ];
+    sys.refresh_memory();
+    refresh_process_list(&mut sys);
+
+    let temperatures = temperature::read_temperatures(&sys);
 
     // Activité réseau
     let networks = Networks::new_with_refreshed_list();
@@ -335,63 +1158,168 @@ async fn get_extended_realtime_stats() -> Result<ExtendedRealtimeStats, String>
     }
 
     // Top processus
-    let cpu_count = sys.cpus().len() as f32;
-    let mut processes: Vec<ProcessInfo> = sys.processes()
-        .values()
-        .map(|process| {
-            let normalized_cpu_usage = process.cpu_usage() / cpu_count;
-            
-            let gpu_usage = match process.name() {
-                name if name.contains("chrome") || name.contains("firefox") || name.contains("edge") => 
-                    (normalized_cpu_usage * 0.3).min(15.0),
-                name if name.contains("game") || name.contains("unity") || name.contains("unreal") => 
-                    (normalized_cpu_usage * 2.0).min(85.0),
-                name if name.contains("nvidia") || name.contains("amd") || name.contains("gpu") => 
-                    (normalized_cpu_usage * 1.5).min(25.0),
-                name if name.contains("WSIMC") => 
-                    (normalized_cpu_usage * 0.1).min(5.0),
-                _ => (normalized_cpu_usage * 0.05).min(3.0),
-            };
-            
-            ProcessInfo {
-                name: process.name().to_string(),
-                pid: process.pid().as_u32(),
-                cpu_usage: normalized_cpu_usage,
-                memory: process.memory(),
-                gpu_usage,
-            }
-        })
-        .collect();
-
+    let mut processes = collect_processes(&sys, users, &config.gpu_heuristic);
     processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap());
     let top_processes = processes.into_iter().take(5).collect();
 
-    Ok(ExtendedRealtimeStats {
+    let binary = config.unit_base.is_binary();
+    let memory_used = config.unit_base.gigabytes(sys.used_memory());
+    let memory_total = config.unit_base.gigabytes(sys.total_memory());
+
+    ExtendedRealtimeStats {
         cpu_usage: sys.global_cpu_info().cpu_usage() as f64,
         memory_usage: (sys.used_memory() as f64 / sys.total_memory() as f64) * 100.0,
-        memory_used_gb: sys.used_memory() as f64 / 1_024_f64.powi(3),
-        memory_total_gb: sys.total_memory() as f64 / 1_024_f64.powi(3),
+        memory_used_gib: binary.then_some(memory_used),
+        memory_total_gib: binary.then_some(memory_total),
+        memory_used_gb: (!binary).then_some(memory_used),
+        memory_total_gb: (!binary).then_some(memory_total),
+        memory_unit: config.unit_base.gigabyte_label().to_string(),
         temperatures,
         network_activity,
         top_processes,
         timestamp: Utc::now(),
-    })
+        self_overhead_percent,
+    }
+}
+
+// Arrête et attend chaque thread de fond ; le scan (spawn_blocking) n'est qu'annulé,
+// il se termine de lui-même au prochain fichier parcouru
+fn stop_background_threads(app: &tauri::AppHandle) {
+    app.state::<monitor::MonitorState>().stop();
+    app.state::<logging::LoggingState>().stop();
+    app.state::<scan::ScanState>().cancel();
+}
+
+#[tauri::command]
+fn shutdown(app: tauri::AppHandle) -> Result<(), String> {
+    stop_background_threads(&app);
+    Ok(())
 }
 
 fn main() {
     tauri::Builder::default()
+        .manage(checkpoint::CheckpointState::default())
+        .manage(config::ConfigState::default())
+        .manage(delta::DeltaState::default())
+        .manage(logging::LoggingState::default())
+        .manage(monitor::MonitorState::default())
+        .manage(replay::ReplayState::default())
+        .manage(scan::ScanState::default())
+        .manage(SharedSystem::default())
+        .manage(UserDirectory::default())
+        .manage(traffic::TrafficBaseline::capture())
+        .setup(|app| {
+            // L'historique démarre avec l'application pour que l'UI l'ait dès l'ouverture
+            app.state::<monitor::MonitorState>()
+                .start(monitor::DEFAULT_INTERVAL, Some(app.handle()));
+            Ok(())
+        })
+        .on_window_event(|event| {
+            if let tauri::WindowEvent::CloseRequested { .. } = event.event() {
+                stop_background_threads(&event.window().app_handle());
+            }
+        })
         .invoke_handler(tauri::generate_handler![
             get_system_info,
             get_cpu_info,
+            get_core_imbalance,
             get_memory_info,
             get_disk_info,
+            get_busiest_disk,
             get_network_info,
+            get_bandwidth,
+            get_default_interface,
+            prime,
             get_real_time_stats,
             get_temperatures,
+            get_hottest_component,
+            is_on_ac_power,
             get_top_processes,
+            get_processes_above,
+            get_processes_by_user,
+            get_zombie_processes,
+            get_orphaned_processes,
+            get_top_disk_writer,
+            get_process_environment,
+            get_high_impact_processes,
             get_advanced_system_info,
-            get_extended_realtime_stats
+            get_capabilities,
+            get_extended_realtime_stats,
+            shutdown,
+            checkpoint::checkpoint,
+            checkpoint::compare_checkpoint,
+            config::get_config,
+            config::set_config,
+            config::set_unit_base,
+            connections::get_connection_summary,
+            container::get_container_info,
+            cpu_stat::get_cpu_breakdown,
+            delta::get_stats_delta,
+            ecc::get_memory_errors,
+            gpu::get_gpu_info,
+            gpu_processes::get_gpu_processes,
+            health::get_health_score,
+            idle::get_idle_time_secs,
+            logging::start_logging,
+            logging::stop_logging,
+            memory_detail::get_memory_detail,
+            memory_modules::get_memory_modules,
+            monitor::start_monitoring,
+            monitor::monitoring_healthy,
+            monitor::get_cpu_history,
+            monitor::get_cpu_histogram,
+            monitor::get_memory_history,
+            monitor::predict_memory_exhaustion,
+            monitor::get_gpu_history,
+            monitor::get_process_creation_rate,
+            monitor::get_packet_loss_rate,
+            power::get_power_profile,
+            process_net::get_process_network_usage,
+            raid::get_raid_status,
+            replay::load_replay,
+            replay::record_replay,
+            replay::stop_replay,
+            scan::scan_largest,
+            scan::cancel_scan,
+            services::get_services,
+            storage::get_storage_devices,
+            traffic::get_session_traffic,
+            usb::get_usb_devices
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_cpu_usage_with_zero_cpus_stays_finite() {
+        // Environnement restreint où sysinfo ne remonte aucun CPU
+        let usages = [0.0_f32, 12.5, 100.0];
+        let mut normalized: Vec<f32> = usages.iter().map(|&u| normalize_cpu_usage(u, 0)).collect();
+
+        assert!(normalized.iter().all(|u| u.is_finite()));
+        assert_eq!(normalized, usages);
+
+        // Le tri utilisé par get_top_processes ne doit pas paniquer
+        normalized.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        assert_eq!(normalized[0], 100.0);
+    }
+
+    #[test]
+    fn normalize_cpu_usage_divides_by_core_count() {
+        assert_eq!(normalize_cpu_usage(400.0, 8), 50.0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn owner_name_falls_back_to_numeric_uid() {
+        // Liste vide : aucun UID ne peut être résolu en nom de compte
+        let users = sysinfo::Users::new();
+        let uid: sysinfo::Uid = "4242".parse().unwrap();
+        assert_eq!(owner_name(&users, Some(&uid)), "4242");
+        assert_eq!(owner_name(&users, None), "unknown");
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Signal de pression mémoire plus précoce que les octets utilisés : les défauts majeurs
+// (page relue depuis le disque) grimpent dès que le cache de pages ne suffit plus.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MemoryDetail {
+    pub minor_faults_per_sec: f64, // Page déjà en mémoire, simple mise à jour des tables
+    pub major_faults_per_sec: f64,
+}
+
+const WINDOW: Duration = Duration::from_millis(500);
+
+#[cfg(target_os = "linux")]
+fn vmstat_counters<const N: usize>(keys: [&str; N]) -> Option<[u64; N]> {
+    let vmstat = std::fs::read_to_string("/proc/vmstat").ok()?;
+    let counter = |key: &str| {
+        vmstat.lines().find_map(|line| {
+            let (name, value) = line.split_once(' ')?;
+            (name == key).then(|| value.trim().parse::<u64>().ok()).flatten()
+        })
+    };
+    let mut values = [0; N];
+    for (value, key) in values.iter_mut().zip(keys) {
+        *value = counter(key)?;
+    }
+    Some(values)
+}
+
+// Compteurs cumulés depuis le démarrage : (tous défauts, défauts majeurs)
+#[cfg(target_os = "linux")]
+fn read_page_faults() -> Option<(u64, u64)> {
+    let [faults, major] = vmstat_counters(["pgfault", "pgmajfault"])?;
+    Some((faults, major))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_page_faults() -> Option<(u64, u64)> {
+    None
+}
+
+// Pages lues depuis le swap + écrites vers le swap, cumulées depuis le démarrage
+#[cfg(target_os = "linux")]
+pub fn read_swapped_pages() -> Option<u64> {
+    let [swapped_in, swapped_out] = vmstat_counters(["pswpin", "pswpout"])?;
+    Some(swapped_in + swapped_out)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_swapped_pages() -> Option<u64> {
+    None
+}
+
+// Taux de défauts de page sur une demi-seconde. `None` hors Linux.
+#[tauri::command]
+pub async fn get_memory_detail() -> Result<Option<MemoryDetail>, String> {
+    let Some((faults_before, major_before)) = read_page_faults() else {
+        return Ok(None);
+    };
+
+    tokio::time::sleep(WINDOW).await;
+
+    Ok(read_page_faults().map(|(faults_after, major_after)| {
+        let major = major_after.saturating_sub(major_before);
+        // pgfault compte aussi les défauts majeurs
+        let minor = faults_after.saturating_sub(faults_before).saturating_sub(major);
+        let per_sec = |count: u64| count as f64 / WINDOW.as_secs_f64();
+        MemoryDetail {
+            minor_faults_per_sec: per_sec(minor),
+            major_faults_per_sec: per_sec(major),
+        }
+    }))
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+// Une barrette installée, d'après la table SMBIOS type 17 (Memory Device)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MemoryModule {
+    pub slot: Option<String>, // Ex. "DIMM_A1", sérigraphié sur la carte mère
+    pub size: u64,
+    pub speed_mts: Option<u32>,            // Vitesse nominale
+    pub configured_speed_mts: Option<u32>, // Vitesse effectivement configurée par le BIOS
+    pub manufacturer: Option<String>,
+    pub memory_type: Option<String>, // Ex. "Ddr4"
+}
+
+#[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+fn read_memory_modules() -> Vec<MemoryModule> {
+    use smbioslib::{MemorySize, MemorySizeExtended, MemorySpeed, MemorySpeedExtended, SMBiosMemoryDevice};
+
+    // Sous Linux, /sys/firmware/dmi/tables n'est lisible qu'en root
+    let Ok(table) = smbioslib::table_load_from_device() else {
+        return Vec::new();
+    };
+
+    let text = |s: smbioslib::SMBiosString| s.ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    let speed = |speed: Option<MemorySpeed>, extended: Option<MemorySpeedExtended>| match speed? {
+        MemorySpeed::MTs(mts) => Some(mts as u32),
+        MemorySpeed::SeeExtendedSpeed => match extended? {
+            MemorySpeedExtended::MTs(mts) => Some(mts),
+            MemorySpeedExtended::SeeSpeed => None,
+        },
+        MemorySpeed::Unknown => None,
+    };
+
+    table
+        .collect::<SMBiosMemoryDevice>()
+        .into_iter()
+        .filter_map(|device| {
+            let size = match device.size()? {
+                MemorySize::Kilobytes(kb) => kb as u64 * 1024,
+                MemorySize::Megabytes(mb) => mb as u64 * 1024 * 1024,
+                MemorySize::SeeExtendedSize => match device.extended_size()? {
+                    MemorySizeExtended::Megabytes(mb) => mb as u64 * 1024 * 1024,
+                    MemorySizeExtended::SeeSize => return None,
+                },
+                // Emplacement vide ou taille inconnue : pas de barrette à décrire
+                MemorySize::NotInstalled | MemorySize::Unknown => return None,
+            };
+            (size > 0).then(|| MemoryModule {
+                slot: text(device.device_locator()),
+                size,
+                speed_mts: speed(device.speed(), device.extended_speed()),
+                configured_speed_mts: speed(device.configured_memory_speed(), device.extended_configured_memory_speed()),
+                manufacturer: text(device.manufacturer()),
+                memory_type: device.memory_type().map(|t| format!("{:?}", t.value)),
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+fn read_memory_modules() -> Vec<MemoryModule> {
+    Vec::new()
+}
+
+// Liste vide si la table DMI n'est pas accessible (droits insuffisants, VM sans SMBIOS)
+#[tauri::command]
+pub fn get_memory_modules() -> Result<Vec<MemoryModule>, String> {
+    Ok(read_memory_modules())
+}
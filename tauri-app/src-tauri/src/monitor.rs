@@ -0,0 +1,673 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use sysinfo::{Networks, Pid, System};
+use tauri::{AppHandle, Manager};
+
+use crate::config::{self, ConfigState};
+use crate::gpu::{self, GpuSample};
+
+// Une heure d'historique à l'intervalle par défaut d'une seconde
+const HISTORY_CAPACITY: usize = 3600;
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(1000);
+// Après ce nombre d'échecs consécutifs, un sous-système n'est plus interrogé qu'une fois
+// par BACKOFF_INTERVAL jusqu'à sa prochaine lecture réussie
+const FAILURES_BEFORE_BACKOFF: u32 = 3;
+const BACKOFF_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MemorySample {
+    pub timestamp: DateTime<Utc>,
+    pub used: u64,
+    pub available: u64,
+    pub swap_used: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CpuSample {
+    pub timestamp: DateTime<Utc>,
+    pub usage: f32, // Charge globale en %
+}
+
+#[derive(Default)]
+pub struct History {
+    pub cpu: VecDeque<CpuSample>,
+    pub memory: VecDeque<MemorySample>,
+    // Un échantillon par GPU et par tick
+    pub gpu: VecDeque<GpuSample>,
+}
+
+fn push_bounded<T>(buffer: &mut VecDeque<T>, sample: T) {
+    if buffer.len() >= HISTORY_CAPACITY {
+        buffer.pop_front();
+    }
+    buffer.push_back(sample);
+}
+
+// Espacement des lectures d'un sous-système en échec (capteur absent, driver planté...),
+// pour ne pas relancer à chaque tick des appels coûteux voués à échouer
+struct Backoff {
+    name: &'static str,
+    failures: u32,
+    retry_at: Option<Instant>,
+}
+
+impl Backoff {
+    fn new(name: &'static str) -> Self {
+        Backoff { name, failures: 0, retry_at: None }
+    }
+
+    fn should_try(&self) -> bool {
+        self.retry_at.is_none_or(|at| Instant::now() >= at)
+    }
+
+    fn record<T>(&mut self, result: Result<T, String>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                if self.retry_at.is_some() {
+                    eprintln!("Monitoring: {} reads recovered", self.name);
+                }
+                self.failures = 0;
+                self.retry_at = None;
+                Some(value)
+            }
+            Err(e) => {
+                self.failures += 1;
+                if self.failures >= FAILURES_BEFORE_BACKOFF {
+                    // Un seul message à l'entrée en backoff, pas un par tentative
+                    if self.retry_at.is_none() {
+                        eprintln!(
+                            "Monitoring: {} failed {} times in a row, retrying every {}s: {}",
+                            self.name,
+                            self.failures,
+                            BACKOFF_INTERVAL.as_secs(),
+                            e
+                        );
+                    }
+                    self.retry_at = Some(Instant::now() + BACKOFF_INTERVAL);
+                }
+                None
+            }
+        }
+    }
+}
+
+// État de backoff de chaque sous-système faillible lu par le thread
+struct Subsystems {
+    gpu: Backoff,
+}
+
+impl Default for Subsystems {
+    fn default() -> Self {
+        Subsystems { gpu: Backoff::new("GPU") }
+    }
+}
+
+// Payload de l'événement `fork-storm-detected`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForkStorm {
+    pub timestamp: DateTime<Utc>,
+    pub new_processes: usize,
+    pub rate_per_sec: f64,
+    // Processus préexistant dont descendent la majorité des nouveaux, s'il y en a un
+    pub ancestor_pid: Option<u32>,
+    pub ancestor_name: Option<String>,
+}
+
+// Pids vus au tick précédent, pour compter les créations d'un tick à l'autre
+#[derive(Default)]
+struct ForkTracker {
+    previous: Option<(HashSet<Pid>, Instant)>,
+    // Vrai tant que le seuil reste dépassé : un seul événement par rafale
+    in_storm: bool,
+}
+
+impl ForkTracker {
+    // Remonte la filiation de chaque nouveau processus jusqu'au premier ancêtre qui
+    // existait déjà : dans une fork bomb, les enfants se reproduisent eux-mêmes.
+    fn common_ancestor(sys: &System, new_pids: &HashSet<Pid>) -> Option<Pid> {
+        let mut roots: HashMap<Pid, usize> = HashMap::new();
+        for &pid in new_pids {
+            let mut current = pid;
+            // Borne contre un cycle de pids recyclés
+            for _ in 0..new_pids.len() {
+                match sys.process(current).and_then(|p| p.parent()) {
+                    Some(parent) if new_pids.contains(&parent) => current = parent,
+                    Some(parent) => {
+                        *roots.entry(parent).or_default() += 1;
+                        break;
+                    }
+                    None => break,
+                }
+            }
+        }
+        roots
+            .into_iter()
+            .max_by_key(|&(_, count)| count)
+            .filter(|&(_, count)| count * 2 > new_pids.len())
+            .map(|(pid, _)| pid)
+    }
+
+    // Met à jour le taux partagé et renvoie l'événement à émettre, le cas échéant
+    fn observe(
+        &mut self,
+        sys: &System,
+        settings: &config::ForkStormSettings,
+        shared: &MonitorShared,
+    ) -> Option<ForkStorm> {
+        let now = Instant::now();
+        let current: HashSet<Pid> = sys.processes().keys().copied().collect();
+        // Premier tick : pas encore de référence
+        let (previous, since) = self.previous.replace((current.clone(), now))?;
+
+        let new_pids: HashSet<Pid> = current.difference(&previous).copied().collect();
+        let rate = new_pids.len() as f64 / now.duration_since(since).as_secs_f64().max(0.001);
+        *shared.process_creation_rate.lock().unwrap() = rate;
+
+        if new_pids.len() <= settings.threshold {
+            self.in_storm = false;
+            return None;
+        }
+        if std::mem::replace(&mut self.in_storm, true) {
+            return None;
+        }
+
+        let ancestor = Self::common_ancestor(sys, &new_pids);
+        Some(ForkStorm {
+            timestamp: Utc::now(),
+            new_processes: new_pids.len(),
+            rate_per_sec: rate,
+            ancestor_pid: ancestor.map(|pid| pid.as_u32()),
+            ancestor_name: ancestor.and_then(|pid| sys.process(pid)).map(|p| p.name().to_string()),
+        })
+    }
+}
+
+// Temps CPU cumulé (utilisateur + noyau) de tout le processus de l'application
+#[cfg(target_os = "linux")]
+fn process_cpu_time() -> Option<Duration> {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+    let micros = |t: libc::timeval| t.tv_sec as u64 * 1_000_000 + t.tv_usec as u64;
+    Some(Duration::from_micros(micros(usage.ru_utime) + micros(usage.ru_stime)))
+}
+
+#[cfg(target_os = "windows")]
+fn process_cpu_time() -> Option<Duration> {
+    use windows_sys::Win32::Foundation::FILETIME;
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, GetProcessTimes};
+
+    let zero = FILETIME { dwLowDateTime: 0, dwHighDateTime: 0 };
+    let (mut creation, mut exit, mut kernel, mut user) = (zero, zero, zero, zero);
+    if unsafe { GetProcessTimes(GetCurrentProcess(), &mut creation, &mut exit, &mut kernel, &mut user) } == 0 {
+        return None;
+    }
+    // Unités de 100 ns
+    let ticks = |t: FILETIME| (t.dwHighDateTime as u64) << 32 | t.dwLowDateTime as u64;
+    Some(Duration::from_nanos((ticks(kernel) + ticks(user)) * 100))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn process_cpu_time() -> Option<Duration> {
+    None
+}
+
+// Part de la capacité CPU totale consommée par l'application entre deux ticks : le thread
+// de monitoring, mais aussi les relevés déclenchés par les commandes du frontend
+#[derive(Default)]
+struct OverheadTracker {
+    previous: Option<(Duration, Instant)>,
+}
+
+impl OverheadTracker {
+    fn observe(&mut self, logical_cpus: usize) -> Option<f32> {
+        let now = (process_cpu_time()?, Instant::now());
+        let (cpu_before, wall_before) = self.previous.replace(now)?;
+        let wall = now.1.duration_since(wall_before).as_secs_f64() * logical_cpus.max(1) as f64;
+        Some((now.0.saturating_sub(cpu_before).as_secs_f64() / wall.max(f64::EPSILON) * 100.0) as f32)
+    }
+}
+
+// Payload de l'événement `packet-loss`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PacketLoss {
+    pub timestamp: DateTime<Utc>,
+    pub interface: String,
+    pub loss_percent: f64,
+    pub lost_packets: u64,
+    pub packets: u64,
+}
+
+// Paquets abandonnés par le noyau ou le pilote (file pleine, tampon saturé), que sysinfo
+// ne distingue pas des paquets reçus
+#[cfg(target_os = "linux")]
+fn interface_dropped_packets(name: &str) -> Option<u64> {
+    let read = |counter: &str| {
+        std::fs::read_to_string(format!("/sys/class/net/{}/statistics/{}", name, counter))
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+    };
+    Some(read("rx_dropped")? + read("tx_dropped")?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn interface_dropped_packets(_name: &str) -> Option<u64> {
+    None
+}
+
+// Compteurs cumulés par interface au tick précédent : (paquets, paquets perdus). Un paquet
+// est perdu s'il est en erreur ou, là où la plateforme les compte, abandonné.
+struct PacketLossTracker {
+    networks: Networks,
+    previous: HashMap<String, (u64, u64)>,
+    // Interfaces au-delà du seuil : un seul événement tant qu'elles y restent
+    alerting: HashSet<String>,
+}
+
+impl Default for PacketLossTracker {
+    fn default() -> Self {
+        PacketLossTracker {
+            networks: Networks::new_with_refreshed_list(),
+            previous: HashMap::new(),
+            alerting: HashSet::new(),
+        }
+    }
+}
+
+impl PacketLossTracker {
+    fn observe(&mut self, settings: &config::PacketLossSettings, shared: &MonitorShared) -> Vec<PacketLoss> {
+        self.networks.refresh_list();
+
+        let mut rates = HashMap::new();
+        let mut events = Vec::new();
+        for (name, network) in self.networks.iter() {
+            let packets = network.total_packets_received() + network.total_packets_transmitted();
+            let lost = network.total_errors_on_received()
+                + network.total_errors_on_transmitted()
+                + interface_dropped_packets(name).unwrap_or(0);
+
+            let Some((packets_before, lost_before)) = self.previous.insert(name.clone(), (packets, lost)) else {
+                continue;
+            };
+            let delta_packets = packets.saturating_sub(packets_before);
+            let delta_lost = lost.saturating_sub(lost_before);
+            // Les paquets perdus ne sont pas toujours comptés dans les paquets reçus
+            let attempted = delta_packets + delta_lost;
+            let loss_percent = if attempted > 0 {
+                delta_lost as f64 / attempted as f64 * 100.0
+            } else {
+                0.0
+            };
+            rates.insert(name.clone(), loss_percent);
+
+            if attempted < settings.min_packets || loss_percent <= settings.threshold_percent {
+                self.alerting.remove(name);
+            } else if self.alerting.insert(name.clone()) {
+                events.push(PacketLoss {
+                    timestamp: Utc::now(),
+                    interface: name.clone(),
+                    loss_percent,
+                    lost_packets: delta_lost,
+                    packets: attempted,
+                });
+            }
+        }
+
+        // Interfaces disparues (VPN coupé, clé débranchée)
+        self.previous.retain(|name, _| rates.contains_key(name));
+        self.alerting.retain(|name| rates.contains_key(name));
+        *shared.packet_loss.lock().unwrap() = rates;
+        events
+    }
+}
+
+#[derive(Default)]
+pub struct MonitorShared {
+    pub running: AtomicBool,
+    // Faux si le dernier tick a paniqué
+    pub healthy: AtomicBool,
+    pub history: Mutex<History>,
+    // Processus créés par seconde au dernier tick
+    pub process_creation_rate: Mutex<f64>,
+    // Charge CPU propre à l'application au dernier tick, en % de la machine
+    pub self_overhead_percent: Mutex<Option<f32>>,
+    // Pourcentage de paquets perdus par interface sur le dernier tick
+    pub packet_loss: Mutex<HashMap<String, f64>>,
+}
+
+fn emit<S: Serialize + Clone>(app: Option<&AppHandle>, event: &str, payload: S) {
+    if let Some(app) = app {
+        if let Err(e) = app.emit_all(event, payload) {
+            eprintln!("Cannot emit {}: {}", event, e);
+        }
+    }
+}
+
+// État Tauri du thread de monitoring : les données partagées vivent dans un Arc
+// pour que le thread puisse les alimenter sans dépendre de l'AppHandle.
+// Comme pour la journalisation, fermer l'émetteur réveille le thread pendant son attente.
+#[derive(Default)]
+pub struct MonitorState {
+    pub shared: Arc<MonitorShared>,
+    thread: Mutex<Option<(Sender<()>, JoinHandle<()>)>>,
+}
+
+impl MonitorState {
+    // Démarre le thread s'il ne tourne pas déjà ; un second appel est sans effet.
+    // L'AppHandle ne sert qu'à émettre les événements et lire la configuration.
+    pub fn start(&self, interval: Duration, app: Option<AppHandle>) {
+        let mut thread = self.thread.lock().unwrap();
+        if self.shared.running.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let shared = Arc::clone(&self.shared);
+        let (stop, stopped) = mpsc::channel::<()>();
+        let handle = std::thread::spawn(move || {
+            let mut sys = System::new();
+            let mut subsystems = Subsystems::default();
+            let mut forks = ForkTracker::default();
+            let mut overhead = OverheadTracker::default();
+            let mut packet_loss = PacketLossTracker::default();
+            loop {
+                // Une panique dans un tick (NVML, capteur...) ne doit pas tuer le flux de stats :
+                // on la journalise, on repart d'un System neuf et on continue au tick suivant.
+                // Tout ce que fait le tick, lecture de la configuration comprise, reste dans catch_unwind.
+                let tick = panic::catch_unwind(AssertUnwindSafe(|| {
+                    let config = app.as_ref().map(|app| app.state::<ConfigState>().snapshot()).unwrap_or_default();
+                    sample(&mut sys, &mut subsystems, &shared);
+                    let storm = forks.observe(&sys, &config.fork_storm, &shared);
+                    let losses = packet_loss.observe(&config.packet_loss, &shared);
+                    let self_overhead = overhead.observe(sys.cpus().len());
+                    *shared.self_overhead_percent.lock().unwrap_or_else(PoisonError::into_inner) = self_overhead;
+                    (storm, losses)
+                }));
+                match tick {
+                    Ok((storm, losses)) => {
+                        shared.healthy.store(true, Ordering::SeqCst);
+                        if let Some(storm) = storm {
+                            emit(app.as_ref(), "fork-storm-detected", storm);
+                        }
+                        for loss in losses {
+                            emit(app.as_ref(), "packet-loss", loss);
+                        }
+                    }
+                    Err(cause) => {
+                        eprintln!("Monitoring tick panicked: {}", panic_message(&cause));
+                        shared.healthy.store(false, Ordering::SeqCst);
+                        shared.history.clear_poison();
+                        shared.process_creation_rate.clear_poison();
+                        shared.packet_loss.clear_poison();
+                        shared.self_overhead_percent.clear_poison();
+                        sys = System::new();
+                        forks = ForkTracker::default();
+                        packet_loss = PacketLossTracker::default();
+                        overhead = OverheadTracker::default();
+                    }
+                }
+                // Toute autre issue que le timeout signifie que stop() a fermé le canal
+                if !matches!(stopped.recv_timeout(interval), Err(RecvTimeoutError::Timeout)) {
+                    break;
+                }
+            }
+        });
+        *thread = Some((stop, handle));
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        let alive = self
+            .thread
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|(_, handle)| !handle.is_finished());
+        alive && self.shared.running.load(Ordering::SeqCst) && self.shared.healthy.load(Ordering::SeqCst)
+    }
+
+    // Arrête le thread et attend sa fin ; sans effet s'il ne tourne pas
+    pub fn stop(&self) {
+        let mut thread = self.thread.lock().unwrap();
+        if let Some((stop, handle)) = thread.take() {
+            drop(stop);
+            let _ = handle.join();
+        }
+        self.shared.running.store(false, Ordering::SeqCst);
+    }
+}
+
+fn panic_message(cause: &Box<dyn Any + Send>) -> &str {
+    cause
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| cause.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+fn sample(sys: &mut System, subsystems: &mut Subsystems, shared: &MonitorShared) {
+    // La charge CPU se mesure entre deux relevés : rien d'exploitable au premier tick
+    let cpu_primed = !sys.cpus().is_empty();
+    sys.refresh_cpu();
+    sys.refresh_memory();
+    sys.refresh_processes();
+
+    let cpu = cpu_primed.then(|| CpuSample {
+        timestamp: Utc::now(),
+        usage: sys.global_cpu_info().cpu_usage(),
+    });
+
+    let memory = MemorySample {
+        timestamp: Utc::now(),
+        used: sys.used_memory(),
+        available: sys.available_memory(),
+        swap_used: sys.used_swap(),
+    };
+
+    // Pas de GPU NVIDIA (ou pas de driver) : l'historique GPU reste simplement vide
+    let gpu_samples = if subsystems.gpu.should_try() {
+        subsystems.gpu.record(gpu::sample_gpus()).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    let mut history = shared.history.lock().unwrap();
+    if let Some(cpu) = cpu {
+        push_bounded(&mut history.cpu, cpu);
+    }
+    push_bounded(&mut history.memory, memory);
+    for gpu_sample in gpu_samples {
+        push_bounded(&mut history.gpu, gpu_sample);
+    }
+}
+
+#[tauri::command]
+pub fn start_monitoring(
+    app: AppHandle,
+    state: tauri::State<'_, MonitorState>,
+    interval_ms: Option<u64>,
+) -> Result<(), String> {
+    let interval = interval_ms
+        .map(|ms| Duration::from_millis(ms.max(100)))
+        .unwrap_or(DEFAULT_INTERVAL);
+    state.start(interval, Some(app));
+    Ok(())
+}
+
+// Faux si le thread ne tourne pas, s'il s'est terminé sans passer par stop()
+// ou si son dernier tick a échoué
+#[tauri::command]
+pub fn monitoring_healthy(state: tauri::State<'_, MonitorState>) -> bool {
+    state.is_healthy()
+}
+
+// Pourcentage de paquets perdus (erreurs et abandons) sur le dernier tick du monitoring
+#[tauri::command]
+pub fn get_packet_loss_rate(state: tauri::State<'_, MonitorState>, interface: String) -> Result<f64, String> {
+    let rates = state.shared.packet_loss.lock().map_err(|e| e.to_string())?;
+    rates
+        .get(&interface)
+        .copied()
+        .ok_or_else(|| format!("No packet loss measurement for interface {}", interface))
+}
+
+#[tauri::command]
+pub fn get_cpu_history(state: tauri::State<'_, MonitorState>) -> Result<Vec<CpuSample>, String> {
+    let history = state.shared.history.lock().map_err(|e| e.to_string())?;
+    Ok(history.cpu.iter().cloned().collect())
+}
+
+// Répartition des charges CPU de l'historique en `buckets` tranches égales de 0 à 100 %
+// (10 tranches : 0–10 %, 10–20 %...) ; 100 % tombe dans la dernière
+#[tauri::command]
+pub fn get_cpu_histogram(state: tauri::State<'_, MonitorState>, buckets: usize) -> Result<Vec<u64>, String> {
+    if buckets == 0 {
+        return Err("Bucket count must be at least 1".to_string());
+    }
+    let history = state.shared.history.lock().map_err(|e| e.to_string())?;
+
+    let mut counts = vec![0u64; buckets];
+    for sample in &history.cpu {
+        let index = (sample.usage.clamp(0.0, 100.0) as f64 / 100.0 * buckets as f64) as usize;
+        counts[index.min(buckets - 1)] += 1;
+    }
+    Ok(counts)
+}
+
+#[tauri::command]
+pub fn get_memory_history(state: tauri::State<'_, MonitorState>) -> Result<Vec<MemorySample>, String> {
+    let history = state.shared.history.lock().map_err(|e| e.to_string())?;
+    Ok(history.memory.iter().cloned().collect())
+}
+
+// Fenêtre de la tendance mémoire : assez longue pour lisser les allocations ponctuelles,
+// assez courte pour refléter une fuite en cours
+const MEMORY_TREND_WINDOW_SECS: i64 = 600;
+const MEMORY_TREND_MIN_SAMPLES: usize = 30;
+// En deçà (octets/s), la pente relève du bruit : la mémoire est considérée stable
+const MEMORY_TREND_MIN_SLOPE: f64 = 1024.0;
+
+// Régression linéaire (moindres carrés) de l'occupation sur le temps, points (secondes, octets).
+// Renvoie les secondes restantes avant que la droite n'atteigne `capacity`.
+fn time_to_exhaustion(points: &[(f64, f64)], capacity: f64) -> Option<u64> {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    if variance == 0.0 {
+        return None;
+    }
+    let slope = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>() / variance;
+    if slope < MEMORY_TREND_MIN_SLOPE {
+        return None;
+    }
+
+    // On projette depuis la valeur lissée au dernier point, pas depuis la dernière mesure brute
+    let last_x = points.last()?.0;
+    let current = mean_y + slope * (last_x - mean_x);
+    Some(((capacity - current).max(0.0) / slope) as u64)
+}
+
+// Secondes estimées avant épuisement de la RAM + swap si l'occupation grimpe sur les dix
+// dernières minutes d'historique ; `None` si elle est stable ou en baisse
+#[tauri::command]
+pub fn predict_memory_exhaustion(state: tauri::State<'_, MonitorState>) -> Result<Option<u64>, String> {
+    let points: Vec<(f64, f64)> = {
+        let history = state.shared.history.lock().map_err(|e| e.to_string())?;
+        let Some(latest) = history.memory.back() else {
+            return Err("Not enough memory history yet".to_string());
+        };
+        let since = latest.timestamp - chrono::Duration::seconds(MEMORY_TREND_WINDOW_SECS);
+        history
+            .memory
+            .iter()
+            .filter(|sample| sample.timestamp >= since)
+            .map(|sample| {
+                let elapsed = (sample.timestamp - since).num_milliseconds() as f64 / 1000.0;
+                (elapsed, (sample.used + sample.swap_used) as f64)
+            })
+            .collect()
+    };
+    if points.len() < MEMORY_TREND_MIN_SAMPLES {
+        return Err("Not enough memory history yet".to_string());
+    }
+
+    let mut sys = System::new();
+    sys.refresh_memory();
+    let capacity = (sys.total_memory() + sys.total_swap()) as f64;
+
+    Ok(time_to_exhaustion(&points, capacity))
+}
+
+#[tauri::command]
+pub fn get_gpu_history(state: tauri::State<'_, MonitorState>) -> Result<Vec<GpuSample>, String> {
+    let history = state.shared.history.lock().map_err(|e| e.to_string())?;
+    Ok(history.gpu.iter().cloned().collect())
+}
+
+// Processus créés par seconde, mesuré par le thread de monitoring (0 avant son second tick)
+#[tauri::command]
+pub fn get_process_creation_rate(state: tauri::State<'_, MonitorState>) -> Result<f64, String> {
+    let rate = state.shared.process_creation_rate.lock().map_err(|e| e.to_string())?;
+    Ok(*rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_start_stop_joins_every_thread() {
+        let state = MonitorState::default();
+        for _ in 0..20 {
+            state.start(Duration::from_millis(100), None);
+            // Un second start pendant l'exécution ne doit pas lancer un autre thread
+            state.start(Duration::from_millis(100), None);
+            assert!(state.shared.running.load(Ordering::SeqCst));
+
+            state.stop();
+            assert!(!state.shared.running.load(Ordering::SeqCst));
+            assert!(state.thread.lock().unwrap().is_none());
+            // Le thread détenait un clone de l'Arc : il n'en reste qu'un s'il s'est bien terminé
+            assert_eq!(Arc::strong_count(&state.shared), 1);
+        }
+    }
+
+    #[test]
+    fn finished_thread_is_not_healthy() {
+        let state = MonitorState::default();
+        state.shared.running.store(true, Ordering::SeqCst);
+        state.shared.healthy.store(true, Ordering::SeqCst);
+        // Thread mort sans passer par stop() : running et healthy sont restés à vrai
+        let (stop, _stopped) = mpsc::channel::<()>();
+        let handle = std::thread::spawn(|| {});
+        while !handle.is_finished() {
+            std::thread::yield_now();
+        }
+        *state.thread.lock().unwrap() = Some((stop, handle));
+        assert!(!state.is_healthy());
+    }
+
+    #[test]
+    fn memory_trend_projects_linear_growth() {
+        // 1 Mo/s à partir de 100 Mo, capacité 1000 Mo : 800 Mo restants au dernier point (t=100)
+        let mb = 1_000_000.0;
+        let growing: Vec<(f64, f64)> = (0..=100).map(|t| (t as f64, (100.0 + t as f64) * mb)).collect();
+        assert_eq!(time_to_exhaustion(&growing, 1000.0 * mb), Some(800));
+
+        let flat: Vec<(f64, f64)> = (0..=100).map(|t| (t as f64, 500.0 * mb)).collect();
+        assert_eq!(time_to_exhaustion(&flat, 1000.0 * mb), None);
+
+        let declining: Vec<(f64, f64)> = (0..=100).map(|t| (t as f64, (500.0 - t as f64) * mb)).collect();
+        assert_eq!(time_to_exhaustion(&declining, 1000.0 * mb), None);
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+// Gouverneur CPU (Linux) ou plan d'alimentation actif (Windows) : explique une
+// fréquence bridée par un mode économie d'énergie.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerProfile {
+    pub name: String,
+    pub source: String, // "cpufreq_governor" ou "power_plan"
+    pub available: Vec<String>,
+}
+
+#[cfg(target_os = "linux")]
+fn read_power_profile() -> Result<PowerProfile, String> {
+    const CPUFREQ: &str = "/sys/devices/system/cpu/cpu0/cpufreq";
+
+    let governor = std::fs::read_to_string(format!("{}/scaling_governor", CPUFREQ))
+        .map_err(|e| format!("cpufreq governor unavailable: {}", e))?;
+    let available = std::fs::read_to_string(format!("{}/scaling_available_governors", CPUFREQ))
+        .map(|list| list.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default();
+
+    Ok(PowerProfile {
+        name: governor.trim().to_string(),
+        source: "cpufreq_governor".to_string(),
+        available,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn read_power_profile() -> Result<PowerProfile, String> {
+    use windows_sys::core::GUID;
+    use windows_sys::Win32::Foundation::{LocalFree, ERROR_SUCCESS};
+    use windows_sys::Win32::System::Power::{PowerGetActiveScheme, PowerReadFriendlyName};
+
+    unsafe {
+        let mut scheme: *mut GUID = std::ptr::null_mut();
+        if PowerGetActiveScheme(0, &mut scheme) != ERROR_SUCCESS {
+            return Err("PowerGetActiveScheme failed".to_string());
+        }
+
+        // Premier appel pour la taille du nom, second pour le nom (UTF-16 terminé par 0)
+        let mut size = 0u32;
+        PowerReadFriendlyName(0, scheme, std::ptr::null(), std::ptr::null(), std::ptr::null_mut(), &mut size);
+        let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+        let status = PowerReadFriendlyName(
+            0,
+            scheme,
+            std::ptr::null(),
+            std::ptr::null(),
+            buffer.as_mut_ptr() as *mut u8,
+            &mut size,
+        );
+        LocalFree(scheme as _);
+
+        if status != ERROR_SUCCESS {
+            return Err("PowerReadFriendlyName failed".to_string());
+        }
+
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        Ok(PowerProfile {
+            name: String::from_utf16_lossy(&buffer[..len]),
+            source: "power_plan".to_string(),
+            available: Vec::new(),
+        })
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn read_power_profile() -> Result<PowerProfile, String> {
+    Err("Power profile is not supported on this platform".to_string())
+}
+
+#[tauri::command]
+pub fn get_power_profile() -> Result<PowerProfile, String> {
+    read_power_profile()
+}
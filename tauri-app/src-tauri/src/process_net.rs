@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+
+// Octets cumulés sur les connexions TCP actuellement ouvertes du processus.
+// Les connexions déjà fermées ne sont plus comptées.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessNetworkInfo {
+    pub pid: u32,
+    pub name: String,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub connections: usize,
+}
+
+// Sous Linux, le noyau expose les compteurs par socket TCP via sock_diag (tcp_info),
+// et /proc/<pid>/fd permet de relier chaque socket (par inode) à son processus.
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::collections::HashMap;
+    use std::io;
+    use std::mem::size_of;
+
+    const NETLINK_SOCK_DIAG: i32 = 4;
+    const SOCK_DIAG_BY_FAMILY: u16 = 20;
+    const INET_DIAG_INFO: u16 = 2;
+    const NLMSG_ERROR: u16 = 2;
+    const NLMSG_DONE: u16 = 3;
+    const NLMSG_HDRLEN: usize = 16;
+    const RTA_HDRLEN: usize = 4;
+    const INET_DIAG_MSG_LEN: usize = 72;
+    const INET_DIAG_MSG_INODE: usize = 68;
+    // Décalages de tcpi_bytes_acked / tcpi_bytes_received dans struct tcp_info
+    const TCPI_BYTES_ACKED: usize = 120;
+    const TCPI_BYTES_RECEIVED: usize = 128;
+
+    // nlmsghdr suivi de inet_diag_req_v2
+    #[repr(C)]
+    struct DiagRequest {
+        len: u32,
+        kind: u16,
+        flags: u16,
+        seq: u32,
+        pid: u32,
+        family: u8,
+        protocol: u8,
+        ext: u8,
+        pad: u8,
+        states: u32,
+        id: [u8; 48],
+    }
+
+    struct Socket(i32);
+
+    impl Drop for Socket {
+        fn drop(&mut self) {
+            unsafe { libc::close(self.0) };
+        }
+    }
+
+    fn align4(len: usize) -> usize {
+        (len + 3) & !3
+    }
+
+    fn read_u32(buf: &[u8], at: usize) -> u32 {
+        u32::from_ne_bytes(buf[at..at + 4].try_into().unwrap())
+    }
+
+    fn read_u64(buf: &[u8], at: usize) -> u64 {
+        u64::from_ne_bytes(buf[at..at + 8].try_into().unwrap())
+    }
+
+    fn parse_diag_msg(msg: &[u8], sockets: &mut HashMap<u64, (u64, u64)>) {
+        if msg.len() < INET_DIAG_MSG_LEN {
+            return;
+        }
+        // Les sockets en TIME_WAIT n'ont plus d'inode ni de processus propriétaire
+        let inode = read_u32(msg, INET_DIAG_MSG_INODE) as u64;
+        if inode == 0 {
+            return;
+        }
+
+        let mut offset = INET_DIAG_MSG_LEN;
+        while offset + RTA_HDRLEN <= msg.len() {
+            let len = u16::from_ne_bytes([msg[offset], msg[offset + 1]]) as usize;
+            let kind = u16::from_ne_bytes([msg[offset + 2], msg[offset + 3]]);
+            if len < RTA_HDRLEN || offset + len > msg.len() {
+                break;
+            }
+
+            let payload = &msg[offset + RTA_HDRLEN..offset + len];
+            if kind == INET_DIAG_INFO && payload.len() >= TCPI_BYTES_RECEIVED + 8 {
+                let sent = read_u64(payload, TCPI_BYTES_ACKED);
+                let received = read_u64(payload, TCPI_BYTES_RECEIVED);
+                sockets.insert(inode, (sent, received));
+            }
+            offset += align4(len);
+        }
+    }
+
+    fn dump_tcp_sockets(family: u8, sockets: &mut HashMap<u64, (u64, u64)>) -> io::Result<()> {
+        let fd = unsafe {
+            libc::socket(libc::AF_NETLINK, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, NETLINK_SOCK_DIAG)
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let socket = Socket(fd);
+
+        let request = DiagRequest {
+            len: size_of::<DiagRequest>() as u32,
+            kind: SOCK_DIAG_BY_FAMILY,
+            flags: (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16,
+            seq: 1,
+            pid: 0,
+            family,
+            protocol: libc::IPPROTO_TCP as u8,
+            ext: 1 << (INET_DIAG_INFO - 1),
+            pad: 0,
+            states: u32::MAX,
+            id: [0; 48],
+        };
+
+        let mut kernel: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+        kernel.nl_family = libc::AF_NETLINK as u16;
+        let sent = unsafe {
+            libc::sendto(
+                socket.0,
+                &request as *const DiagRequest as *const libc::c_void,
+                size_of::<DiagRequest>(),
+                0,
+                &kernel as *const libc::sockaddr_nl as *const libc::sockaddr,
+                size_of::<libc::sockaddr_nl>() as u32,
+            )
+        };
+        if sent < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            let received = unsafe { libc::recv(socket.0, buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0) };
+            if received < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let received = received as usize;
+            let mut offset = 0;
+            while offset + NLMSG_HDRLEN <= received {
+                let len = read_u32(&buf, offset) as usize;
+                let kind = u16::from_ne_bytes([buf[offset + 4], buf[offset + 5]]);
+                if len < NLMSG_HDRLEN || offset + len > received {
+                    break;
+                }
+
+                let payload = &buf[offset + NLMSG_HDRLEN..offset + len];
+                match kind {
+                    NLMSG_DONE => return Ok(()),
+                    NLMSG_ERROR => {
+                        let errno = payload.get(..4).map(|b| i32::from_ne_bytes(b.try_into().unwrap()));
+                        return Err(io::Error::from_raw_os_error(-errno.unwrap_or(-libc::EIO)));
+                    }
+                    _ => parse_diag_msg(payload, sockets),
+                }
+                offset += align4(len);
+            }
+        }
+    }
+
+    // Inodes des sockets ouvertes par chaque processus lisible (les processus
+    // d'autres utilisateurs ne sont visibles qu'en root)
+    fn socket_owners() -> HashMap<u64, u32> {
+        let mut owners = HashMap::new();
+        let Ok(proc_dir) = std::fs::read_dir("/proc") else {
+            return owners;
+        };
+
+        for entry in proc_dir.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|n| n.parse::<u32>().ok()) else {
+                continue;
+            };
+            let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+                continue;
+            };
+            for fd in fds.flatten() {
+                let Ok(target) = std::fs::read_link(fd.path()) else {
+                    continue;
+                };
+                let inode = target
+                    .to_str()
+                    .and_then(|t| t.strip_prefix("socket:["))
+                    .and_then(|t| t.strip_suffix(']'))
+                    .and_then(|t| t.parse::<u64>().ok());
+                if let Some(inode) = inode {
+                    owners.insert(inode, pid);
+                }
+            }
+        }
+
+        owners
+    }
+
+    // pid -> (octets envoyés, octets reçus, connexions)
+    pub fn usage_by_pid() -> io::Result<HashMap<u32, (u64, u64, usize)>> {
+        let mut sockets = HashMap::new();
+        dump_tcp_sockets(libc::AF_INET as u8, &mut sockets)?;
+        dump_tcp_sockets(libc::AF_INET6 as u8, &mut sockets)?;
+
+        let owners = socket_owners();
+        let mut usage: HashMap<u32, (u64, u64, usize)> = HashMap::new();
+        for (inode, (sent, received)) in sockets {
+            if let Some(&pid) = owners.get(&inode) {
+                let entry = usage.entry(pid).or_default();
+                entry.0 += sent;
+                entry.1 += received;
+                entry.2 += 1;
+            }
+        }
+
+        Ok(usage)
+    }
+}
+
+// Vrai si la plateforme permet l'attribution et que le noyau accepte la requête sock_diag
+pub fn is_supported() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        linux::usage_by_pid().is_ok()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        false
+    }
+}
+
+// Liste vide sur les plateformes sans attribution par processus
+#[tauri::command]
+pub fn get_process_network_usage() -> Result<Vec<ProcessNetworkInfo>, String> {
+    #[cfg(target_os = "linux")]
+    {
+        use sysinfo::{Pid, System};
+
+        let usage = linux::usage_by_pid().map_err(|e| e.to_string())?;
+        let mut sys = System::new();
+        sys.refresh_processes();
+
+        let mut processes: Vec<ProcessNetworkInfo> = usage
+            .into_iter()
+            .map(|(pid, (bytes_sent, bytes_received, connections))| ProcessNetworkInfo {
+                pid,
+                name: sys
+                    .process(Pid::from_u32(pid))
+                    .map(|p| p.name().to_string())
+                    .unwrap_or_default(),
+                bytes_sent,
+                bytes_received,
+                connections,
+            })
+            .collect();
+
+        processes.sort_by_key(|p| std::cmp::Reverse(p.bytes_sent + p.bytes_received));
+        Ok(processes)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RaidArrayStatus {
+    pub name: String,          // "md0" ou nom du disque virtuel Storage Spaces
+    pub level: Option<String>, // "raid1", "raid5"... ou "Mirror", "Parity" sous Windows
+    pub devices: Option<usize>,
+    pub active_devices: Option<usize>,
+    pub degraded: bool,
+    pub rebuilding: bool,
+    // "resync", "recovery", "reshape" ou "check" (Linux)
+    pub sync_action: Option<String>,
+    pub sync_progress_percent: Option<f64>,
+}
+
+// Blocs de /proc/mdstat, ex. :
+//   md1 : active raid5 sdd1[3](F) sdc1[1] sdb1[0]
+//         3906764800 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [UU_]
+//         [==>..................]  recovery = 12.6% (246563456/1953382400) finish=158.3min
+#[cfg(target_os = "linux")]
+fn parse_mdstat(content: &str) -> Vec<RaidArrayStatus> {
+    let mut arrays: Vec<RaidArrayStatus> = Vec::new();
+
+    for line in content.lines() {
+        if let Some((name, description)) = line.split_once(" : ").filter(|(name, _)| name.starts_with("md")) {
+            // "active raid1 ..." ou "active (auto-read-only) raid1 ..." ; un tableau inactif n'a pas de niveau
+            let mut words = description.split_whitespace().skip(1);
+            let level = words.find(|w| !w.starts_with('(')).filter(|w| !w.contains('['));
+            arrays.push(RaidArrayStatus {
+                name: name.trim().to_string(),
+                level: level.map(str::to_string),
+                ..Default::default()
+            });
+            continue;
+        }
+        let Some(array) = arrays.last_mut() else {
+            continue;
+        };
+
+        // "[3/2]" : membres attendus / membres actifs
+        let counts = line.split_whitespace().find_map(|w| {
+            let (total, active) = w.strip_prefix('[')?.strip_suffix(']')?.split_once('/')?;
+            Some((total.parse::<usize>().ok()?, active.parse::<usize>().ok()?))
+        });
+        if let Some((total, active)) = counts {
+            array.devices = Some(total);
+            array.active_devices = Some(active);
+            array.degraded = active < total;
+        }
+
+        // "[==>...]  recovery = 12.6% (...)" ("[>...]" en tout début), ou "resync=DELAYED"
+        // en attente d'un autre tableau
+        let mut progress = line.trim_start();
+        if ["[=", "[>", "[."].iter().any(|bar| progress.starts_with(bar)) {
+            progress = progress
+                .split_once(']')
+                .map(|(_, rest)| rest.trim_start())
+                .unwrap_or_default();
+        }
+        if let Some(action) = ["resync", "recovery", "reshape", "check"]
+            .into_iter()
+            .find(|a| progress.starts_with(a))
+        {
+            let value = progress[action.len()..]
+                .trim_start()
+                .trim_start_matches('=')
+                .trim_start();
+            array.sync_action = Some(action.to_string());
+            array.sync_progress_percent = value
+                .split_once('%')
+                .and_then(|(percent, _)| percent.trim().parse().ok());
+            // Un check ne reconstruit rien, il vérifie seulement la cohérence ; un resync
+            // DELAYED n'a pas encore commencé
+            array.rebuilding = action != "check" && array.sync_progress_percent.is_some();
+        }
+    }
+
+    arrays
+}
+
+#[cfg(target_os = "linux")]
+fn read_raid_status() -> Result<Vec<RaidArrayStatus>, String> {
+    // Sans le module md, le fichier n'existe pas : aucun RAID logiciel
+    match std::fs::read_to_string("/proc/mdstat") {
+        Ok(content) => Ok(parse_mdstat(&content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("Cannot read /proc/mdstat: {}", e)),
+    }
+}
+
+// Disques virtuels Storage Spaces. La progression des réparations n'est exposée que par
+// les tâches du pool (MSFT_StorageJob), sans lien fiable avec un disque : elle reste None.
+#[cfg(target_os = "windows")]
+fn read_raid_status() -> Result<Vec<RaidArrayStatus>, String> {
+    use wmi::{COMLibrary, WMIConnection};
+
+    // OperationalStatus : 3 Degraded, 11 In Service (réparation en cours) ; HealthStatus : 0 Healthy
+    const DEGRADED: u16 = 3;
+    const IN_SERVICE: u16 = 11;
+    const HEALTHY: u16 = 0;
+
+    #[derive(Deserialize)]
+    #[serde(rename = "MSFT_VirtualDisk", rename_all = "PascalCase")]
+    struct VirtualDisk {
+        friendly_name: String,
+        resiliency_setting_name: Option<String>,
+        health_status: u16,
+        operational_status: Vec<u16>,
+    }
+
+    let com = COMLibrary::new().map_err(|e| format!("COM initialization failed: {}", e))?;
+    let wmi = WMIConnection::with_namespace_path("ROOT\\Microsoft\\Windows\\Storage", com)
+        .map_err(|e| format!("Storage WMI namespace unavailable: {}", e))?;
+    let disks: Vec<VirtualDisk> = wmi
+        .raw_query("SELECT FriendlyName, ResiliencySettingName, HealthStatus, OperationalStatus FROM MSFT_VirtualDisk")
+        .map_err(|e| format!("Cannot query Storage Spaces: {}", e))?;
+
+    Ok(disks
+        .into_iter()
+        .map(|disk| RaidArrayStatus {
+            name: disk.friendly_name,
+            level: disk.resiliency_setting_name,
+            degraded: disk.health_status != HEALTHY || disk.operational_status.contains(&DEGRADED),
+            rebuilding: disk.operational_status.contains(&IN_SERVICE),
+            ..Default::default()
+        })
+        .collect())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn read_raid_status() -> Result<Vec<RaidArrayStatus>, String> {
+    Err("RAID status is not supported on this platform".to_string())
+}
+
+// Liste vide si la machine n'a aucun RAID logiciel
+#[tauri::command]
+pub async fn get_raid_status() -> Result<Vec<RaidArrayStatus>, String> {
+    // WMI initialise COM pour le thread appelant : on reste hors des threads du runtime
+    tokio::task::spawn_blocking(read_raid_status)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    const MDSTAT: &str = "Personalities : [raid1] [raid5]
+md0 : active raid1 sdb1[1] sda1[0]
+      1046528 blocks super 1.2 [2/2] [UU]
+
+md1 : active raid5 sdd1[3](F) sdc1[1] sdb2[0]
+      3906764800 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [UU_]
+      [==>..................]  recovery = 12.6% (246563456/1953382400) finish=158.3min speed=20000K/sec
+
+md2 : active raid1 sdf1[1] sde1[0]
+      1046528 blocks [2/2] [UU]
+      [>....................]  resync =  5.0% (52326/1046528) finish=0.3min speed=52326K/sec
+
+unused devices: <none>
+";
+
+    #[test]
+    fn parses_active_array() {
+        let arrays = parse_mdstat(MDSTAT);
+        assert_eq!(arrays.len(), 3);
+
+        let md0 = &arrays[0];
+        assert_eq!(md0.name, "md0");
+        assert_eq!(md0.level.as_deref(), Some("raid1"));
+        assert_eq!((md0.devices, md0.active_devices), (Some(2), Some(2)));
+        assert!(!md0.degraded);
+        assert!(!md0.rebuilding);
+        assert_eq!(md0.sync_action, None);
+    }
+
+    #[test]
+    fn parses_degraded_array_in_recovery() {
+        let md1 = &parse_mdstat(MDSTAT)[1];
+        assert_eq!(md1.level.as_deref(), Some("raid5"));
+        assert_eq!((md1.devices, md1.active_devices), (Some(3), Some(2)));
+        assert!(md1.degraded);
+        assert!(md1.rebuilding);
+        assert_eq!(md1.sync_action.as_deref(), Some("recovery"));
+        assert_eq!(md1.sync_progress_percent, Some(12.6));
+    }
+
+    #[test]
+    fn parses_resyncing_array() {
+        let md2 = &parse_mdstat(MDSTAT)[2];
+        assert!(!md2.degraded);
+        assert!(md2.rebuilding);
+        assert_eq!(md2.sync_action.as_deref(), Some("resync"));
+        assert_eq!(md2.sync_progress_percent, Some(5.0));
+    }
+}
@@ -0,0 +1,92 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::Mutex;
+
+use crate::ExtendedRealtimeStats;
+
+// Mode de fonctionnement du pipeline de stats étendues.
+// Les enregistrements sont au format JSON Lines : un ExtendedRealtimeStats par ligne,
+// ce qui permet d'ajouter les échantillons au fil de l'eau sans réécrire le fichier.
+#[derive(Default)]
+pub enum ReplayMode {
+    #[default]
+    Live,
+    Recording(File),
+    Replaying {
+        samples: Vec<ExtendedRealtimeStats>,
+        position: usize,
+    },
+}
+
+#[derive(Default)]
+pub struct ReplayState {
+    pub mode: Mutex<ReplayMode>,
+}
+
+impl ReplayState {
+    // Prochain échantillon enregistré si un replay est en cours ; il boucle une fois la fin atteinte
+    pub fn next_sample(&self) -> Option<ExtendedRealtimeStats> {
+        let mut mode = self.mode.lock().unwrap();
+        match &mut *mode {
+            ReplayMode::Replaying { samples, position } => {
+                let sample = samples.get(*position).cloned();
+                *position = (*position + 1) % samples.len();
+                sample
+            }
+            _ => None,
+        }
+    }
+
+    pub fn record(&self, stats: &ExtendedRealtimeStats) -> Result<(), String> {
+        let mut mode = self.mode.lock().map_err(|e| e.to_string())?;
+        if let ReplayMode::Recording(file) = &mut *mode {
+            let line = serde_json::to_string(stats).map_err(|e| e.to_string())?;
+            writeln!(file, "{}", line).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+fn read_samples(path: &str) -> Result<Vec<ExtendedRealtimeStats>, String> {
+    let file = File::open(path).map_err(|e| format!("Cannot open replay file {}: {}", path, e))?;
+
+    BufReader::new(file)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(l) if l.trim().is_empty()))
+        .map(|(n, line)| {
+            let line = line.map_err(|e| e.to_string())?;
+            serde_json::from_str(&line)
+                .map(ExtendedRealtimeStats::with_legacy_units_fixed)
+                .map_err(|e| format!("Invalid sample at line {}: {}", n + 1, e))
+        })
+        .collect()
+}
+
+// Les appels suivants à get_extended_realtime_stats renvoient les échantillons du fichier
+#[tauri::command]
+pub fn load_replay(state: tauri::State<'_, ReplayState>, path: String) -> Result<usize, String> {
+    let samples = read_samples(&path)?;
+    if samples.is_empty() {
+        return Err(format!("Replay file {} contains no samples", path));
+    }
+
+    let count = samples.len();
+    *state.mode.lock().map_err(|e| e.to_string())? = ReplayMode::Replaying { samples, position: 0 };
+    Ok(count)
+}
+
+// Chaque appel à get_extended_realtime_stats est ajouté au fichier (écrasé au démarrage)
+#[tauri::command]
+pub fn record_replay(state: tauri::State<'_, ReplayState>, path: String) -> Result<(), String> {
+    let file = File::create(&path).map_err(|e| format!("Cannot create replay file {}: {}", path, e))?;
+    *state.mode.lock().map_err(|e| e.to_string())? = ReplayMode::Recording(file);
+    Ok(())
+}
+
+// Arrête l'enregistrement ou le replay et revient aux mesures réelles
+#[tauri::command]
+pub fn stop_replay(state: tauri::State<'_, ReplayState>) -> Result<(), String> {
+    *state.mode.lock().map_err(|e| e.to_string())? = ReplayMode::Live;
+    Ok(())
+}
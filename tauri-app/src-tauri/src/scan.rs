@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use walkdir::WalkDir;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FileEntry {
+    pub path: String,
+    pub size: u64,
+    pub is_dir: bool,
+}
+
+#[derive(Default)]
+pub struct ScanState {
+    cancel: Arc<AtomicBool>,
+}
+
+// Parcourt toute l'arborescence : la taille d'un dossier est la somme de tous ses fichiers.
+// Seuls les `limit` plus gros fichiers sont conservés pour borner la mémoire,
+// les dossiers étant de toute façon moins nombreux.
+fn walk(root: &Path, limit: usize, cancel: &AtomicBool) -> Result<Vec<FileEntry>, String> {
+    let mut dir_sizes: HashMap<PathBuf, u64> = HashMap::new();
+    let mut largest_files: BinaryHeap<Reverse<(u64, PathBuf)>> = BinaryHeap::new();
+
+    // Les liens symboliques ne sont pas suivis pour éviter les boucles et les doublons
+    for entry in WalkDir::new(root).follow_links(false).into_iter().flatten() {
+        if cancel.load(Ordering::Relaxed) {
+            return Err("Scan cancelled".to_string());
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        for ancestor in entry.path().ancestors().skip(1) {
+            if ancestor == root {
+                break;
+            }
+            *dir_sizes.entry(ancestor.to_path_buf()).or_default() += size;
+        }
+
+        largest_files.push(Reverse((size, entry.into_path())));
+        if largest_files.len() > limit {
+            largest_files.pop();
+        }
+    }
+
+    let mut entries: Vec<FileEntry> = dir_sizes
+        .into_iter()
+        .map(|(path, size)| FileEntry { path: path.to_string_lossy().to_string(), size, is_dir: true })
+        .chain(largest_files.into_iter().map(|Reverse((size, path))| FileEntry {
+            path: path.to_string_lossy().to_string(),
+            size,
+            is_dir: false,
+        }))
+        .collect();
+
+    entries.sort_by_key(|e| Reverse(e.size));
+    entries.truncate(limit);
+    Ok(entries)
+}
+
+// Opération potentiellement longue (plusieurs minutes sur un disque entier) :
+// elle tourne hors du runtime async et peut être interrompue par cancel_scan.
+#[tauri::command]
+pub async fn scan_largest(
+    state: tauri::State<'_, ScanState>,
+    path: String,
+    limit: usize,
+) -> Result<Vec<FileEntry>, String> {
+    let root = PathBuf::from(&path);
+    if !root.is_dir() {
+        return Err(format!("{} is not a directory", path));
+    }
+
+    state.cancel.store(false, Ordering::Relaxed);
+    let cancel = Arc::clone(&state.cancel);
+
+    tokio::task::spawn_blocking(move || walk(&root, limit, &cancel))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+impl ScanState {
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+#[tauri::command]
+pub fn cancel_scan(state: tauri::State<'_, ScanState>) -> Result<(), String> {
+    state.cancel();
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+// États normalisés sur le vocabulaire de systemd, Windows y est ramené :
+// active_state vaut "active", "inactive", "failed", "activating" ou "deactivating" ;
+// sub_state détaille l'état propre à la plateforme ("running", "exited", "paused"...).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServiceInfo {
+    pub name: String, // Ex. "nginx.service" ou "Spooler"
+    pub description: String,
+    pub active_state: String,
+    pub sub_state: String,
+}
+
+// Signature a(ssssssouso) de org.freedesktop.systemd1.Manager.ListUnits : nom, description,
+// état de chargement, état actif, sous-état, unité suivie, chemin, job (id, type, chemin)
+#[cfg(target_os = "linux")]
+type SystemdUnit = (
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    zbus::zvariant::OwnedObjectPath,
+    u32,
+    String,
+    zbus::zvariant::OwnedObjectPath,
+);
+
+#[cfg(target_os = "linux")]
+async fn list_services() -> Result<Vec<ServiceInfo>, String> {
+    let connection = zbus::Connection::system()
+        .await
+        .map_err(|e| format!("Cannot connect to the system D-Bus: {}", e))?;
+    let reply = connection
+        .call_method(
+            Some("org.freedesktop.systemd1"),
+            "/org/freedesktop/systemd1",
+            Some("org.freedesktop.systemd1.Manager"),
+            "ListUnits",
+            &(),
+        )
+        .await
+        .map_err(|e| format!("Cannot list systemd units: {}", e))?;
+    let units: Vec<SystemdUnit> = reply.body().deserialize().map_err(|e| e.to_string())?;
+
+    // ListUnits ne renvoie que les unités chargées : un service jamais démarré n'y figure pas
+    Ok(units
+        .into_iter()
+        .filter(|unit| unit.0.ends_with(".service"))
+        .map(|(name, description, _load, active_state, sub_state, ..)| ServiceInfo {
+            name,
+            description,
+            active_state,
+            sub_state,
+        })
+        .collect())
+}
+
+#[cfg(target_os = "windows")]
+pub unsafe fn wide_to_string(ptr: *const u16) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    let mut len = 0;
+    while *ptr.add(len) != 0 {
+        len += 1;
+    }
+    String::from_utf16_lossy(std::slice::from_raw_parts(ptr, len))
+}
+
+#[cfg(target_os = "windows")]
+fn windows_states(current: u32, exit_code: u32) -> (&'static str, &'static str) {
+    use windows_sys::Win32::System::Services::{
+        SERVICE_CONTINUE_PENDING, SERVICE_PAUSED, SERVICE_PAUSE_PENDING, SERVICE_RUNNING, SERVICE_START_PENDING,
+        SERVICE_STOPPED, SERVICE_STOP_PENDING,
+    };
+
+    match current {
+        SERVICE_RUNNING => ("active", "running"),
+        SERVICE_START_PENDING => ("activating", "start_pending"),
+        SERVICE_CONTINUE_PENDING => ("activating", "continue_pending"),
+        SERVICE_STOP_PENDING => ("deactivating", "stop_pending"),
+        SERVICE_PAUSE_PENDING => ("deactivating", "pause_pending"),
+        SERVICE_PAUSED => ("inactive", "paused"),
+        // Un service arrêté avec un code de sortie non nul s'est terminé en erreur
+        SERVICE_STOPPED if exit_code != 0 => ("failed", "stopped"),
+        _ => ("inactive", "stopped"),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn enumerate_services() -> Result<Vec<ServiceInfo>, String> {
+    use windows_sys::Win32::Foundation::{GetLastError, ERROR_MORE_DATA};
+    use windows_sys::Win32::System::Services::{
+        CloseServiceHandle, EnumServicesStatusExW, OpenSCManagerW, ENUM_SERVICE_STATUS_PROCESSW, SC_ENUM_PROCESS_INFO,
+        SC_MANAGER_ENUMERATE_SERVICE, SERVICE_STATE_ALL, SERVICE_WIN32,
+    };
+
+    unsafe {
+        let manager = OpenSCManagerW(std::ptr::null(), std::ptr::null(), SC_MANAGER_ENUMERATE_SERVICE);
+        if manager == 0 {
+            return Err(format!("Cannot open the Service Control Manager (error {})", GetLastError()));
+        }
+
+        // Tampon de u64 pour respecter l'alignement des structures renvoyées
+        let mut buffer = vec![0u64; 64 * 1024 / 8];
+        let mut resume = 0u32;
+        let mut services = Vec::new();
+        loop {
+            let mut needed = 0u32;
+            let mut returned = 0u32;
+            let ok = EnumServicesStatusExW(
+                manager,
+                SC_ENUM_PROCESS_INFO,
+                SERVICE_WIN32,
+                SERVICE_STATE_ALL,
+                buffer.as_mut_ptr() as *mut u8,
+                (buffer.len() * 8) as u32,
+                &mut needed,
+                &mut returned,
+                &mut resume,
+                std::ptr::null(),
+            );
+            let error = GetLastError();
+
+            let entries =
+                std::slice::from_raw_parts(buffer.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW, returned as usize);
+            for entry in entries {
+                let status = entry.ServiceStatusProcess;
+                let (active_state, sub_state) = windows_states(status.dwCurrentState, status.dwWin32ExitCode);
+                services.push(ServiceInfo {
+                    name: wide_to_string(entry.lpServiceName),
+                    description: wide_to_string(entry.lpDisplayName),
+                    active_state: active_state.to_string(),
+                    sub_state: sub_state.to_string(),
+                });
+            }
+
+            if ok != 0 {
+                break;
+            }
+            // ERROR_MORE_DATA : la suite est reprise à `resume`, avec un tampon agrandi si besoin
+            if error != ERROR_MORE_DATA {
+                CloseServiceHandle(manager);
+                return Err(format!("Cannot enumerate services (error {})", error));
+            }
+            if needed as usize > buffer.len() * 8 {
+                buffer.resize(needed as usize / 8 + 1, 0);
+            }
+        }
+
+        CloseServiceHandle(manager);
+        Ok(services)
+    }
+}
+
+// L'énumération du SCM est bloquante : elle tourne hors du runtime async
+#[cfg(target_os = "windows")]
+async fn list_services() -> Result<Vec<ServiceInfo>, String> {
+    tokio::task::spawn_blocking(enumerate_services).await.map_err(|e| e.to_string())?
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+async fn list_services() -> Result<Vec<ServiceInfo>, String> {
+    Err("Services are not supported on this platform".to_string())
+}
+
+#[tauri::command]
+pub async fn get_services() -> Result<Vec<ServiceInfo>, String> {
+    let mut services = list_services().await?;
+    services.sort_by_key(|s| s.name.to_lowercase());
+    Ok(services)
+}
@@ -0,0 +1,353 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Vue "physique" du stockage : chaque disque avec ses partitions, là où
+// get_disk_info ne liste que les systèmes de fichiers montés.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StorageDevice {
+    pub name: String,
+    pub model: Option<String>,
+    pub size: u64, // 0 si inconnue
+    pub is_rotational: Option<bool>,
+    pub is_removable: Option<bool>,
+    pub partitions: Vec<Partition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Partition {
+    pub name: String,
+    pub size: u64,
+    pub mount_point: Option<String>,
+    pub file_system: Option<String>,
+}
+
+#[cfg(target_os = "linux")]
+fn read_storage_devices() -> Result<Vec<StorageDevice>, String> {
+    use std::fs;
+    use std::path::Path;
+
+    fn read_trimmed(path: &Path) -> Option<String> {
+        fs::read_to_string(path).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+    }
+
+    // Les tailles dans /sys/block sont toujours en secteurs de 512 octets
+    fn size_bytes(path: &Path) -> u64 {
+        read_trimmed(&path.join("size")).and_then(|s| s.parse::<u64>().ok()).unwrap_or(0) * 512
+    }
+
+    // /dev/<nom> -> (point de montage, système de fichiers), premier montage retenu
+    let mut mounts: HashMap<String, (String, String)> = HashMap::new();
+    for line in fs::read_to_string("/proc/mounts").unwrap_or_default().lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if let [source, target, fs_type, ..] = fields[..] {
+            if let Some(dev) = source.strip_prefix("/dev/") {
+                mounts.entry(dev.to_string()).or_insert((target.to_string(), fs_type.to_string()));
+            }
+        }
+    }
+
+    let partition_of = |name: &str, path: &Path| Partition {
+        name: name.to_string(),
+        size: size_bytes(path),
+        mount_point: mounts.get(name).map(|(m, _)| m.clone()),
+        file_system: mounts.get(name).map(|(_, f)| f.clone()),
+    };
+
+    let entries = fs::read_dir("/sys/block").map_err(|e| format!("Cannot read /sys/block: {}", e))?;
+    let mut devices = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        // Périphériques virtuels sans disque physique derrière
+        if ["loop", "ram", "zram", "dm-", "md"].iter().any(|p| name.starts_with(p)) {
+            continue;
+        }
+
+        let path = entry.path();
+        let mut partitions: Vec<Partition> = fs::read_dir(&path)
+            .map(|children| {
+                children
+                    .flatten()
+                    .filter(|c| c.path().join("partition").exists())
+                    .map(|c| partition_of(&c.file_name().to_string_lossy(), &c.path()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        partitions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Disque formaté sans table de partitions : le disque entier est le système de fichiers
+        if partitions.is_empty() && mounts.contains_key(&name) {
+            partitions.push(partition_of(&name, &path));
+        }
+
+        devices.push(StorageDevice {
+            model: read_trimmed(&path.join("device/model")),
+            size: size_bytes(&path),
+            is_rotational: read_trimmed(&path.join("queue/rotational")).map(|r| r == "1"),
+            is_removable: read_trimmed(&path.join("removable")).map(|r| r == "1"),
+            partitions,
+            name,
+        });
+    }
+
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(devices)
+}
+
+#[cfg(target_os = "windows")]
+use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+
+#[cfg(target_os = "windows")]
+fn open_device(path: &str) -> Option<HANDLE> {
+    use windows_sys::Win32::Foundation::INVALID_HANDLE_VALUE;
+    use windows_sys::Win32::Storage::FileSystem::{CreateFileW, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING};
+
+    let wide: Vec<u16> = path.encode_utf16().chain(std::iter::once(0)).collect();
+    // Accès 0 : suffisant pour les IOCTL de requête, sans droits administrateur
+    let handle = unsafe {
+        CreateFileW(
+            wide.as_ptr(),
+            0,
+            FILE_SHARE_READ | FILE_SHARE_WRITE,
+            std::ptr::null(),
+            OPEN_EXISTING,
+            0,
+            0,
+        )
+    };
+    (handle != INVALID_HANDLE_VALUE).then_some(handle)
+}
+
+#[cfg(target_os = "windows")]
+fn device_query<I, T>(handle: HANDLE, code: u32, input: Option<&I>, out: &mut T) -> bool {
+    use windows_sys::Win32::System::IO::DeviceIoControl;
+
+    let mut returned = 0u32;
+    unsafe {
+        DeviceIoControl(
+            handle,
+            code,
+            input.map_or(std::ptr::null(), |i| i as *const I as *const _),
+            input.map_or(0, |_| std::mem::size_of::<I>() as u32),
+            out as *mut T as *mut _,
+            std::mem::size_of::<T>() as u32,
+            &mut returned,
+            std::ptr::null_mut(),
+        ) != 0
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn device_ioctl<T>(handle: HANDLE, code: u32, out: &mut T) -> bool {
+    device_query::<(), T>(handle, code, None, out)
+}
+
+// Sous Windows, chaque volume monté est rattaché à son disque physique via ses
+// extents (IOCTL_VOLUME_GET_VOLUME_DISK_EXTENTS). Un volume réparti sur plusieurs
+// disques (volume agrégé) est rattaché à chacun d'eux.
+#[cfg(target_os = "windows")]
+fn read_storage_devices() -> Result<Vec<StorageDevice>, String> {
+    use std::collections::BTreeMap;
+    use windows_sys::Win32::Storage::FileSystem::IOCTL_VOLUME_GET_VOLUME_DISK_EXTENTS;
+    use windows_sys::Win32::System::Ioctl::{GET_LENGTH_INFORMATION, IOCTL_DISK_GET_LENGTH_INFO, VOLUME_DISK_EXTENTS};
+
+    // Assez de place pour quelques extents au-delà du premier
+    #[repr(C)]
+    struct Extents {
+        header: VOLUME_DISK_EXTENTS,
+        more: [windows_sys::Win32::System::Ioctl::DISK_EXTENT; 7],
+    }
+
+    let mut devices: BTreeMap<u32, StorageDevice> = BTreeMap::new();
+    for disk in sysinfo::Disks::new_with_refreshed_list().iter() {
+        let mount = disk.mount_point().to_string_lossy().to_string();
+        let letter = mount.trim_end_matches('\\');
+        let Some(volume) = open_device(&format!("\\\\.\\{}", letter)) else {
+            continue;
+        };
+        let mut extents: Extents = unsafe { std::mem::zeroed() };
+        let ok = device_ioctl(volume, IOCTL_VOLUME_GET_VOLUME_DISK_EXTENTS, &mut extents);
+        unsafe { CloseHandle(volume) };
+        if !ok {
+            continue;
+        }
+
+        let count = (extents.header.NumberOfDiskExtents as usize).min(8);
+        let disk_numbers = std::iter::once(extents.header.Extents[0])
+            .chain(extents.more.iter().copied())
+            .take(count)
+            .map(|e| e.DiskNumber);
+
+        for number in disk_numbers {
+            let device = devices.entry(number).or_insert_with(|| {
+                let mut length = GET_LENGTH_INFORMATION { Length: 0 };
+                let size = open_device(&format!("\\\\.\\PhysicalDrive{}", number))
+                    .map(|h| {
+                        let ok = device_ioctl(h, IOCTL_DISK_GET_LENGTH_INFO, &mut length);
+                        unsafe { CloseHandle(h) };
+                        if ok { length.Length as u64 } else { 0 }
+                    })
+                    .unwrap_or(0);
+                StorageDevice {
+                    name: format!("PhysicalDrive{}", number),
+                    model: None,
+                    size,
+                    is_rotational: None,
+                    is_removable: Some(disk.is_removable()),
+                    partitions: Vec::new(),
+                }
+            });
+            device.partitions.push(Partition {
+                name: disk.name().to_string_lossy().to_string(),
+                size: disk.total_space(),
+                mount_point: Some(mount.clone()),
+                file_system: Some(disk.file_system().to_string_lossy().to_string()),
+            });
+        }
+    }
+
+    Ok(devices.into_values().collect())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn read_storage_devices() -> Result<Vec<StorageDevice>, String> {
+    Err("Storage device layout is not supported on this platform".to_string())
+}
+
+// Compteurs cumulés depuis le démarrage ; deux relevés successifs donnent débit et saturation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IoCounters {
+    pub read_bytes: u64,
+    pub written_bytes: u64,
+    // Temps (ms) pendant lequel au moins une requête était en cours, et même temps pondéré
+    // par le nombre de requêtes en attente. Linux uniquement.
+    pub io_time_ms: Option<u64>,
+    pub weighted_io_time_ms: Option<u64>,
+}
+
+// Compteurs de chaque disque monté, indexés par point de montage
+#[cfg(target_os = "linux")]
+pub fn io_counters(disks: &sysinfo::Disks) -> HashMap<String, IoCounters> {
+    // /proc/diskstats : secteurs lus en 6e colonne, écrits en 10e, toujours de 512 octets ;
+    // temps d'I/O en 13e colonne et temps pondéré en 14e
+    let mut devices: HashMap<String, IoCounters> = HashMap::new();
+    for line in std::fs::read_to_string("/proc/diskstats").unwrap_or_default().lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 {
+            continue;
+        }
+        let field = |i: usize| fields.get(i).and_then(|f| f.parse::<u64>().ok());
+        if let (Some(read), Some(written)) = (field(5), field(9)) {
+            devices.insert(
+                fields[2].to_string(),
+                IoCounters {
+                    read_bytes: read * 512,
+                    written_bytes: written * 512,
+                    io_time_ms: field(12),
+                    weighted_io_time_ms: field(13),
+                },
+            );
+        }
+    }
+
+    disks
+        .iter()
+        .filter_map(|disk| {
+            // /dev/mapper/x est un lien vers /dev/dm-N, le nom utilisé par diskstats
+            let device = std::fs::canonicalize(disk.name()).ok()?;
+            let counters = *devices.get(device.file_name()?.to_str()?)?;
+            Some((disk.mount_point().to_string_lossy().to_string(), counters))
+        })
+        .collect()
+}
+
+// IOCTL_DISK_PERFORMANCE sur le volume ; les compteurs sont actifs par défaut depuis Windows 8
+#[cfg(target_os = "windows")]
+pub fn io_counters(disks: &sysinfo::Disks) -> HashMap<String, IoCounters> {
+    use windows_sys::Win32::System::Ioctl::{DISK_PERFORMANCE, IOCTL_DISK_PERFORMANCE};
+
+    disks
+        .iter()
+        .filter_map(|disk| {
+            let mount = disk.mount_point().to_string_lossy().to_string();
+            let volume = open_device(&format!("\\\\.\\{}", mount.trim_end_matches('\\')))?;
+            let mut performance: DISK_PERFORMANCE = unsafe { std::mem::zeroed() };
+            let ok = device_ioctl(volume, IOCTL_DISK_PERFORMANCE, &mut performance);
+            unsafe { CloseHandle(volume) };
+            let counters = IoCounters {
+                read_bytes: performance.BytesRead as u64,
+                written_bytes: performance.BytesWritten as u64,
+                ..Default::default()
+            };
+            ok.then_some((mount, counters))
+        })
+        .collect()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn io_counters(_disks: &sysinfo::Disks) -> HashMap<String, IoCounters> {
+    HashMap::new()
+}
+
+// Température des SSD NVMe, exposée par le contrôleur via hwmon (en millidegrés).
+// Les disques SATA/ATA passent par SMART, non géré ici.
+#[cfg(target_os = "linux")]
+pub fn nvme_temperature(disk: &sysinfo::Disk) -> Option<f32> {
+    // nvme0n1p2 -> contrôleur nvme0
+    let device = std::fs::canonicalize(disk.name()).ok()?;
+    let name = device.file_name()?.to_str()?;
+    let id: String = name.strip_prefix("nvme")?.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let controller = std::path::Path::new("/sys/class/nvme").join(format!("nvme{}", id));
+
+    std::fs::read_dir(&controller)
+        .ok()?
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("hwmon"))
+        .find_map(|hwmon| std::fs::read_to_string(hwmon.path().join("temp1_input")).ok())
+        .and_then(|raw| raw.trim().parse::<f32>().ok())
+        .map(|millidegrees| millidegrees / 1000.0)
+}
+
+// StorageDeviceTemperatureProperty (Windows 10+), limité aux disques sur bus NVMe
+#[cfg(target_os = "windows")]
+pub fn nvme_temperature(disk: &sysinfo::Disk) -> Option<f32> {
+    use windows_sys::Win32::Storage::FileSystem::BusTypeNvme;
+    use windows_sys::Win32::System::Ioctl::{
+        PropertyStandardQuery, StorageDeviceProperty, StorageDeviceTemperatureProperty, IOCTL_STORAGE_QUERY_PROPERTY,
+        STORAGE_DEVICE_DESCRIPTOR, STORAGE_PROPERTY_QUERY, STORAGE_TEMPERATURE_DATA_DESCRIPTOR,
+    };
+
+    let mount = disk.mount_point().to_string_lossy().to_string();
+    let volume = open_device(&format!("\\\\.\\{}", mount.trim_end_matches('\\')))?;
+    let query = |property| STORAGE_PROPERTY_QUERY {
+        PropertyId: property,
+        QueryType: PropertyStandardQuery,
+        AdditionalParameters: [0],
+    };
+
+    let mut descriptor: STORAGE_DEVICE_DESCRIPTOR = unsafe { std::mem::zeroed() };
+    let is_nvme = device_query(volume, IOCTL_STORAGE_QUERY_PROPERTY, Some(&query(StorageDeviceProperty)), &mut descriptor)
+        && descriptor.BusType == BusTypeNvme;
+
+    let mut temperature: STORAGE_TEMPERATURE_DATA_DESCRIPTOR = unsafe { std::mem::zeroed() };
+    let ok = is_nvme
+        && device_query(
+            volume,
+            IOCTL_STORAGE_QUERY_PROPERTY,
+            Some(&query(StorageDeviceTemperatureProperty)),
+            &mut temperature,
+        );
+    unsafe { CloseHandle(volume) };
+
+    // L'entrée 0 est la température composite du contrôleur
+    (ok && temperature.InfoCount > 0).then(|| temperature.TemperatureInfo[0].Temperature as f32)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn nvme_temperature(_disk: &sysinfo::Disk) -> Option<f32> {
+    None
+}
+
+#[tauri::command]
+pub fn get_storage_devices() -> Result<Vec<StorageDevice>, String> {
+    read_storage_devices()
+}
@@ -0,0 +1,193 @@
+use sysinfo::{Components, System};
+
+use crate::TemperatureInfo;
+
+// Les pilotes nomment différemment les mêmes capteurs : coretemp (Intel) expose
+// "Package id 0" et "Core N", k10temp/zenpower (AMD) "Tctl", "Tdie" et "TccdN".
+// sysinfo préfixe en plus le label du nom du pilote ("coretemp Core 0").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuVendor {
+    Intel,
+    Amd,
+    Unknown,
+}
+
+impl CpuVendor {
+    pub fn detect(vendor_id: &str, brand: &str) -> Self {
+        match vendor_id {
+            "GenuineIntel" => CpuVendor::Intel,
+            "AuthenticAMD" => CpuVendor::Amd,
+            // vendor_id vide (ARM, VM) : on se rabat sur la marque
+            _ if brand.contains("Intel") => CpuVendor::Intel,
+            _ if brand.contains("AMD") => CpuVendor::Amd,
+            _ => CpuVendor::Unknown,
+        }
+    }
+}
+
+const CPU_DRIVERS: [&str; 3] = ["coretemp", "k10temp", "zenpower"];
+
+fn package_name(id: &str) -> String {
+    // Un seul socket dans l'immense majorité des cas : pas de numéro pour le premier
+    match id.trim() {
+        "0" => "CPU Package".to_string(),
+        id => format!("CPU Package {}", id),
+    }
+}
+
+fn intel_label(sensor: &str) -> Option<String> {
+    // "Physical id" sur les noyaux antérieurs à 3.x
+    if let Some(id) = sensor.strip_prefix("Package id ").or_else(|| sensor.strip_prefix("Physical id ")) {
+        return Some(package_name(id));
+    }
+    let core: u32 = sensor.strip_prefix("Core ")?.trim().parse().ok()?;
+    Some(format!("Core {}", core))
+}
+
+fn amd_label(sensor: &str) -> Option<String> {
+    match sensor {
+        "Tctl" | "Tdie" => Some(package_name("0")),
+        // AMD ne mesure pas chaque cœur, seulement chaque chiplet (CCD)
+        _ => {
+            let ccd: u32 = sensor.strip_prefix("Tccd")?.parse().ok()?;
+            Some(format!("CPU CCD {}", ccd))
+        }
+    }
+}
+
+// Nom homogène d'un capteur CPU ; les autres capteurs (NVMe, GPU, ACPI...) gardent le leur
+pub fn normalize_label(label: &str, vendor: CpuVendor) -> String {
+    let sensor = match label.split_once(' ') {
+        Some((driver, sensor)) if CPU_DRIVERS.contains(&driver) => sensor.trim(),
+        _ => label.trim(),
+    };
+    let normalized = match vendor {
+        CpuVendor::Intel => intel_label(sensor),
+        CpuVendor::Amd => amd_label(sensor),
+        CpuVendor::Unknown => intel_label(sensor).or_else(|| amd_label(sensor)),
+    };
+    normalized.unwrap_or_else(|| label.to_string())
+}
+
+pub fn read_temperatures(sys: &System) -> Vec<TemperatureInfo> {
+    let vendor = sys
+        .cpus()
+        .first()
+        .map(|cpu| CpuVendor::detect(cpu.vendor_id(), cpu.brand()))
+        .unwrap_or(CpuVendor::Unknown);
+
+    let components = Components::new_with_refreshed_list();
+    // Tctl peut inclure un décalage (jusqu'à +27 °C sur Threadripper) : Tdie, quand il
+    // existe, est la vraie température du die et remplace Tctl
+    let has_tdie = components.iter().any(|c| c.label().ends_with("Tdie"));
+
+    components
+        .iter()
+        .filter(|c| !(has_tdie && c.label().ends_with("Tctl")))
+        // Lecture hwmon échouée : sysinfo renvoie NaN, qui serait sérialisé en null
+        .filter(|c| c.temperature().is_finite())
+        .map(|c| TemperatureInfo {
+            component: normalize_label(c.label(), vendor),
+            temperature: c.temperature(),
+            // max() de sysinfo est le pic observé, pas un seuil
+            max_temperature: None,
+            critical_temperature: c.critical(),
+        })
+        .collect()
+}
+
+// Capteur le plus proche de son seuil critique, en proportion : un disque à 70 °C sur 80
+// passe devant un CPU à 75 °C sur 100. Sans aucun seuil connu, le plus chaud en absolu.
+pub fn hottest_component(temperatures: Vec<TemperatureInfo>) -> Option<TemperatureInfo> {
+    // total_cmp classe NaN au-dessus de tout : une lecture échouée passerait pour la plus chaude
+    let temperatures: Vec<TemperatureInfo> = temperatures.into_iter().filter(|t| t.temperature.is_finite()).collect();
+    let ratio = |t: &TemperatureInfo| t.critical_temperature.filter(|c| *c > 0.0).map(|c| t.temperature / c);
+
+    if temperatures.iter().any(|t| ratio(t).is_some()) {
+        temperatures
+            .into_iter()
+            .filter_map(|t| Some((ratio(&t)?, t)))
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, t)| t)
+    } else {
+        temperatures.into_iter().max_by(|a, b| a.temperature.total_cmp(&b.temperature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_vendor_from_id_or_brand() {
+        assert_eq!(CpuVendor::detect("GenuineIntel", ""), CpuVendor::Intel);
+        assert_eq!(CpuVendor::detect("AuthenticAMD", ""), CpuVendor::Amd);
+        assert_eq!(CpuVendor::detect("", "AMD Ryzen 7 5800X 8-Core Processor"), CpuVendor::Amd);
+        assert_eq!(CpuVendor::detect("", "Apple M2"), CpuVendor::Unknown);
+    }
+
+    #[test]
+    fn normalizes_intel_coretemp_labels() {
+        let vendor = CpuVendor::Intel;
+        assert_eq!(normalize_label("coretemp Package id 0", vendor), "CPU Package");
+        assert_eq!(normalize_label("coretemp Package id 1", vendor), "CPU Package 1");
+        assert_eq!(normalize_label("coretemp Physical id 0", vendor), "CPU Package");
+        assert_eq!(normalize_label("coretemp Core 0", vendor), "Core 0");
+        assert_eq!(normalize_label("coretemp Core 12", vendor), "Core 12");
+        assert_eq!(normalize_label("Package id 0", vendor), "CPU Package");
+    }
+
+    #[test]
+    fn normalizes_amd_k10temp_and_zenpower_labels() {
+        let vendor = CpuVendor::Amd;
+        assert_eq!(normalize_label("k10temp Tctl", vendor), "CPU Package");
+        assert_eq!(normalize_label("k10temp Tdie", vendor), "CPU Package");
+        assert_eq!(normalize_label("k10temp Tccd1", vendor), "CPU CCD 1");
+        assert_eq!(normalize_label("zenpower Tccd2", vendor), "CPU CCD 2");
+    }
+
+    #[test]
+    fn keeps_labels_outside_the_vendor_mapping() {
+        // Un "Core 0" sur AMD ne vient pas de k10temp : pas de renommage
+        assert_eq!(normalize_label("Core 0", CpuVendor::Amd), "Core 0");
+        assert_eq!(normalize_label("nvme Composite WD_BLACK SN850X temp1", CpuVendor::Amd), "nvme Composite WD_BLACK SN850X temp1");
+        assert_eq!(normalize_label("amdgpu edge", CpuVendor::Amd), "amdgpu edge");
+        assert_eq!(normalize_label("acpitz temp1", CpuVendor::Intel), "acpitz temp1");
+        // Vendeur inconnu : les deux tables sont essayées
+        assert_eq!(normalize_label("k10temp Tctl", CpuVendor::Unknown), "CPU Package");
+        assert_eq!(normalize_label("coretemp Core 3", CpuVendor::Unknown), "Core 3");
+    }
+
+    fn sensor(component: &str, temperature: f32, critical: Option<f32>) -> TemperatureInfo {
+        TemperatureInfo {
+            component: component.to_string(),
+            temperature,
+            max_temperature: None,
+            critical_temperature: critical,
+        }
+    }
+
+    #[test]
+    fn hottest_component_is_ranked_against_its_critical_threshold() {
+        let sensors = vec![
+            sensor("CPU Package", 75.0, Some(100.0)),
+            sensor("nvme Composite", 70.0, Some(80.0)),
+            // Sans seuil, un capteur ne peut pas être comparé aux autres
+            sensor("acpitz temp1", 90.0, None),
+        ];
+        assert_eq!(hottest_component(sensors).unwrap().component, "nvme Composite");
+
+        let unrated = vec![sensor("acpitz temp1", 45.0, None), sensor("acpitz temp2", 52.0, None)];
+        assert_eq!(hottest_component(unrated).unwrap().component, "acpitz temp2");
+        assert!(hottest_component(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn hottest_component_ignores_failed_readings() {
+        let sensors = vec![sensor("nvme Composite", f32::NAN, Some(80.0)), sensor("CPU Package", 60.0, Some(100.0))];
+        assert_eq!(hottest_component(sensors).unwrap().component, "CPU Package");
+
+        let unrated = vec![sensor("acpitz temp1", f32::NAN, None), sensor("acpitz temp2", 40.0, None)];
+        assert_eq!(hottest_component(unrated).unwrap().component, "acpitz temp2");
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use sysinfo::Networks;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InterfaceTraffic {
+    pub name: String,
+    pub received: u64,
+    pub transmitted: u64,
+}
+
+// Octets échangés depuis le lancement de l'application, et non depuis le démarrage
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SessionTraffic {
+    pub since: DateTime<Utc>,
+    pub received: u64,
+    pub transmitted: u64,
+    pub interfaces: Vec<InterfaceTraffic>,
+}
+
+// Compteurs de chaque interface relevés au lancement
+pub struct TrafficBaseline {
+    started_at: DateTime<Utc>,
+    counters: HashMap<String, (u64, u64)>,
+}
+
+impl TrafficBaseline {
+    pub fn capture() -> Self {
+        let networks = Networks::new_with_refreshed_list();
+        TrafficBaseline {
+            started_at: Utc::now(),
+            counters: networks
+                .iter()
+                .map(|(name, network)| (name.clone(), (network.total_received(), network.total_transmitted())))
+                .collect(),
+        }
+    }
+}
+
+// Un compteur inférieur à sa référence a été remis à zéro (interface recréée, pilote
+// rechargé) : tout ce qu'il affiche a alors été échangé pendant la session
+fn since_baseline(current: u64, baseline: u64) -> u64 {
+    if current >= baseline {
+        current - baseline
+    } else {
+        current
+    }
+}
+
+#[tauri::command]
+pub fn get_session_traffic(baseline: tauri::State<'_, TrafficBaseline>) -> Result<SessionTraffic, String> {
+    let networks = Networks::new_with_refreshed_list();
+
+    // Une interface apparue en cours de session (VPN, clé USB) part de zéro
+    let mut interfaces: Vec<InterfaceTraffic> = networks
+        .iter()
+        .map(|(name, network)| {
+            let (received, transmitted) = baseline.counters.get(name).copied().unwrap_or((0, 0));
+            InterfaceTraffic {
+                name: name.clone(),
+                received: since_baseline(network.total_received(), received),
+                transmitted: since_baseline(network.total_transmitted(), transmitted),
+            }
+        })
+        .collect();
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(SessionTraffic {
+        since: baseline.started_at,
+        received: interfaces.iter().map(|i| i.received).sum(),
+        transmitted: interfaces.iter().map(|i| i.transmitted).sum(),
+        interfaces,
+    })
+}
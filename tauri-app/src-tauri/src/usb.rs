@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UsbDevice {
+    pub bus: u8,
+    pub address: u8,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    // None quand le descripteur n'a pas de chaîne ou que l'ouverture du périphérique est
+    // refusée (droits udev sous Linux) : le VID/PID reste toujours disponible
+    pub manufacturer: Option<String>,
+    pub product: Option<String>,
+}
+
+#[tauri::command]
+pub fn get_usb_devices() -> Result<Vec<UsbDevice>, String> {
+    use rusb::UsbContext;
+
+    // Contexte explicite : le contexte global de rusb panique si libusb ne peut pas
+    // s'initialiser (pas de /dev/bus/usb dans un conteneur, par exemple)
+    let context = rusb::Context::new().map_err(|e| format!("USB access unavailable: {}", e))?;
+    let devices = context.devices().map_err(|e| format!("Cannot enumerate USB devices: {}", e))?;
+
+    let mut usb_devices: Vec<UsbDevice> = devices
+        .iter()
+        .filter_map(|device| {
+            // Le descripteur de périphérique est lu sans ouvrir le périphérique
+            let descriptor = device.device_descriptor().ok()?;
+            let handle = device.open().ok();
+            let read = |read_string: fn(&rusb::DeviceHandle<rusb::Context>, &rusb::DeviceDescriptor) -> rusb::Result<String>| {
+                handle
+                    .as_ref()
+                    .and_then(|h| read_string(h, &descriptor).ok())
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+            };
+
+            Some(UsbDevice {
+                bus: device.bus_number(),
+                address: device.address(),
+                vendor_id: descriptor.vendor_id(),
+                product_id: descriptor.product_id(),
+                manufacturer: read(|h, d| h.read_manufacturer_string_ascii(d)),
+                product: read(|h, d| h.read_product_string_ascii(d)),
+            })
+        })
+        .collect();
+
+    usb_devices.sort_by_key(|d| (d.bus, d.address));
+    Ok(usb_devices)
+}
@@ -56,18 +56,24 @@ interface DiskInfo {
   file_system: string;
 }
 
+// memory_used_gib/memory_total_gib en base 1024, memory_used_gb/memory_total_gb en base 1000
 interface RealtimeStats {
   cpu_usage: number;
   memory_usage: number;
-  memory_used_gb: number;
-  memory_total_gb: number;
+  memory_used_gib?: number;
+  memory_total_gib?: number;
+  memory_used_gb?: number;
+  memory_total_gb?: number;
 }
 
 interface ExtendedRealtimeStats {
   cpu_usage: number;
   memory_usage: number;
-  memory_used_gb: number;
-  memory_total_gb: number;
+  memory_used_gib?: number;
+  memory_total_gib?: number;
+  memory_used_gb?: number;
+  memory_total_gb?: number;
+  memory_unit: string;
   temperatures: TemperatureInfo[];
   network_activity: Record<string, [number, number]>;
   top_processes: ProcessInfo[];
@@ -97,9 +103,11 @@ interface ChartDataPoint {
 }
 
 // Utility functions
-const formatBytes = (bytes: number): string => {
-  const gb = bytes / (1024 ** 3);
-  return gb < 1 ? `${(bytes / (1024 ** 2)).toFixed(0)}MB` : `${gb.toFixed(1)}GB`;
+// base 1024 : MiB/GiB, base 1000 : MB/GB (réglage unit_base du backend)
+const formatBytes = (bytes: number, base: number = 1024): string => {
+  const suffix = base === 1024 ? 'iB' : 'B';
+  const gb = bytes / (base ** 3);
+  return gb < 1 ? `${(bytes / (base ** 2)).toFixed(0)}M${suffix}` : `${gb.toFixed(1)}G${suffix}`;
 };
 
 const formatUptime = (seconds: number): string => {
@@ -260,7 +268,7 @@ const RealtimeMonitor: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
             <div className={`text-sm transition-colors duration-200 ${
               isDarkMode ? 'text-gray-400' : 'text-gray-600'
             }`}>
-              {extendedStats.memory_used_gb.toFixed(1)} GB / {extendedStats.memory_total_gb.toFixed(1)} GB
+              {(extendedStats.memory_used_gib ?? extendedStats.memory_used_gb ?? 0).toFixed(1)} {extendedStats.memory_unit} / {(extendedStats.memory_total_gib ?? extendedStats.memory_total_gb ?? 0).toFixed(1)} {extendedStats.memory_unit}
             </div>
           </div>
         </MetricCard>
@@ -325,22 +333,25 @@ const SystemOverview: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
   const [cpuInfo, setCpuInfo] = useState<CpuInfo | null>(null);
   const [memoryInfo, setMemoryInfo] = useState<MemoryInfo | null>(null);
   const [diskInfo, setDiskInfo] = useState<DiskInfo[]>([]);
+  const [unitBase, setUnitBase] = useState(1024);
   const [isLoading, setIsLoading] = useState(true);
 
   const fetchAllInfo = async () => {
     try {
       setIsLoading(true);
-      const [system, cpu, memory, disks] = await Promise.all([
+      const [system, cpu, memory, disks, config] = await Promise.all([
         invoke<SystemInfo>('get_system_info'),
         invoke<CpuInfo>('get_cpu_info'),
         invoke<MemoryInfo>('get_memory_info'),
         invoke<DiskInfo[]>('get_disk_info'),
+        invoke<{ unit_base: number }>('get_config'),
       ]);
       
       setSystemInfo(system);
       setCpuInfo(cpu);
       setMemoryInfo(memory);
       setDiskInfo(disks);
+      setUnitBase(config.unit_base);
     } catch (error) {
       console.error('Error fetching system info:', error);
     } finally {
@@ -466,7 +477,7 @@ const SystemOverview: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
               }`}>Total:</span>
               <span className={`font-medium transition-colors duration-200 ${
                 isDarkMode ? 'text-gray-200' : 'text-gray-800'
-              }`}>{formatBytes(memoryInfo.total)}</span>
+              }`}>{formatBytes(memoryInfo.total, unitBase)}</span>
             </div>
             <div className="flex justify-between">
               <span className={`transition-colors duration-200 ${
@@ -474,7 +485,7 @@ const SystemOverview: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
               }`}>Utilisée:</span>
               <span className={`font-medium transition-colors duration-200 ${
                 isDarkMode ? 'text-gray-200' : 'text-gray-800'
-              }`}>{formatBytes(memoryInfo.used)}</span>
+              }`}>{formatBytes(memoryInfo.used, unitBase)}</span>
             </div>
             <div className="flex justify-between">
               <span className={`transition-colors duration-200 ${
@@ -482,7 +493,7 @@ const SystemOverview: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
               }`}>Disponible:</span>
               <span className={`font-medium transition-colors duration-200 ${
                 isDarkMode ? 'text-gray-200' : 'text-gray-800'
-              }`}>{formatBytes(memoryInfo.available)}</span>
+              }`}>{formatBytes(memoryInfo.available, unitBase)}</span>
             </div>
             <div className="flex justify-between">
               <span className={`transition-colors duration-200 ${
@@ -491,7 +502,7 @@ const SystemOverview: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
               <span className={`font-medium transition-colors duration-200 ${
                 isDarkMode ? 'text-gray-200' : 'text-gray-800'
               }`}>
-                {formatBytes(memoryInfo.swap_total)}
+                {formatBytes(memoryInfo.swap_total, unitBase)}
                 {memoryInfo.swap_total > 0 && memoryInfo.swap_used > 0 && (
                   <span className="text-blue-400 ml-2">
                     ({((memoryInfo.swap_used / memoryInfo.swap_total) * 100).toFixed(1)}%)
@@ -533,8 +544,8 @@ const SystemOverview: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
                 <div className={`flex justify-between text-xs mt-1 transition-colors duration-200 ${
                   isDarkMode ? 'text-gray-400' : 'text-gray-600'
                 }`}>
-                  <span>{formatBytes(disk.used_space)} utilisé</span>
-                  <span>{formatBytes(disk.total_space)} total</span>
+                  <span>{formatBytes(disk.used_space, unitBase)} utilisé</span>
+                  <span>{formatBytes(disk.total_space, unitBase)} total</span>
                 </div>
               </div>
             ))}
@@ -547,6 +558,7 @@ const SystemOverview: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
 
 const App: React.FC = () => {
   const [activeTab, setActiveTab] = useState<'monitor' | 'overview' | 'processes'>('monitor');
+  const [isPrimed, setIsPrimed] = useState(false);
   const [isDarkMode, setIsDarkMode] = useState(() => {
     const saved = localStorage.getItem('darkMode');
     return saved ? JSON.parse(saved) : true;
@@ -567,6 +579,13 @@ const App: React.FC = () => {
     }
   }, []);
 
+  // Les mesures CPU lisent 0 tant que le backend n'a pas fait ses deux premiers relevés
+  useEffect(() => {
+    invoke('prime')
+      .catch(error => console.error('Error priming system stats:', error))
+      .finally(() => setIsPrimed(true));
+  }, []);
+
   const toggleTheme = () => {
     setIsDarkMode(!isDarkMode);
   };
@@ -666,9 +685,14 @@ const App: React.FC = () => {
           exit={{ opacity: 0, x: -20 }}
           transition={{ duration: 0.3 }}
         >
-          {activeTab === 'monitor' && <RealtimeMonitor isDarkMode={isDarkMode} />}
-          {activeTab === 'overview' && <SystemOverview isDarkMode={isDarkMode} />}
-          {activeTab === 'processes' && <ProcessPage isDarkMode={isDarkMode} />}
+          {!isPrimed && (
+            <div className={`text-center py-12 animate-pulse ${isDarkMode ? 'text-gray-400' : 'text-gray-500'}`}>
+              Initialisation des mesures...
+            </div>
+          )}
+          {isPrimed && activeTab === 'monitor' && <RealtimeMonitor isDarkMode={isDarkMode} />}
+          {isPrimed && activeTab === 'overview' && <SystemOverview isDarkMode={isDarkMode} />}
+          {isPrimed && activeTab === 'processes' && <ProcessPage isDarkMode={isDarkMode} />}
         </motion.div>
       </main>
     </div>
@@ -45,9 +45,11 @@ const MetricCard: React.FC<MetricCardProps> = ({ title, icon, children, classNam
   </motion.div>
 );
 
-const formatBytes = (bytes: number): string => {
-  const gb = bytes / (1024 ** 3);
-  return gb < 1 ? `${(bytes / (1024 ** 2)).toFixed(0)}MB` : `${gb.toFixed(1)}GB`;
+// base 1024 : MiB/GiB, base 1000 : MB/GB (réglage unit_base du backend)
+const formatBytes = (bytes: number, base: number = 1024): string => {
+  const suffix = base === 1024 ? 'iB' : 'B';
+  const gb = bytes / (base ** 3);
+  return gb < 1 ? `${(bytes / (base ** 2)).toFixed(0)}M${suffix}` : `${gb.toFixed(1)}G${suffix}`;
 };
 
 export const ProcessPage: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) => {
@@ -56,6 +58,7 @@ export const ProcessPage: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) =
   const [sortBy, setSortBy] = useState<'cpu' | 'memory' | 'name' | 'gpu'>('cpu');
   const [sortOrder, setSortOrder] = useState<'asc' | 'desc'>('desc');
   const [searchTerm, setSearchTerm] = useState('');
+  const [unitBase, setUnitBase] = useState(1024);
 
   const fetchProcesses = async () => {
     try {
@@ -70,6 +73,9 @@ export const ProcessPage: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) =
   };
 
   useEffect(() => {
+    invoke<{ unit_base: number }>('get_config')
+      .then(config => setUnitBase(config.unit_base))
+      .catch(error => console.error('Error fetching config:', error));
     fetchProcesses();
     const interval = setInterval(fetchProcesses, 3000);
     return () => clearInterval(interval);
@@ -289,7 +295,7 @@ export const ProcessPage: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) =
                       <span className={`font-medium transition-colors duration-200 ${
                         isDarkMode ? 'text-gray-300' : 'text-gray-700'
                       }`}>
-                        {formatBytes(process.memory)}
+                        {formatBytes(process.memory, unitBase)}
                       </span>
                     </td>
                     <td className="p-4 text-right">
@@ -355,7 +361,7 @@ export const ProcessPage: React.FC<{ isDarkMode: boolean }> = ({ isDarkMode }) =
           <div className={`text-2xl font-bold transition-colors duration-200 ${
             isDarkMode ? 'text-gray-200' : 'text-gray-800'
           }`}>
-            {formatBytes(processes.reduce((acc, p) => acc + p.memory, 0))}
+            {formatBytes(processes.reduce((acc, p) => acc + p.memory, 0), unitBase)}
           </div>
         </div>
       </div>